@@ -0,0 +1,131 @@
+use crate::common::*;
+use crate::secret::Secret;
+
+/// Maps a directory group or user to a local BMC role
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RoleMapping {
+    pub local_role: Option<String>,
+    pub remote_group: Option<String>,
+    pub remote_user: Option<String>,
+}
+
+/// An external directory the BMC can authenticate accounts against
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExternalAccountProvider {
+    pub account_provider_type: Option<String>,
+    #[serde(default)]
+    pub remote_role_mapping: Vec<RoleMapping>,
+    #[serde(default)]
+    pub service_addresses: Vec<String>,
+    pub service_enabled: Option<bool>,
+}
+
+impl ExternalAccountProvider {
+    /// Whether the directory is both enabled and pointed at a server
+    pub fn is_configured(&self) -> bool {
+        self.service_enabled == Some(true) && self.service_addresses.iter().any(|a| !a.is_empty())
+    }
+}
+
+/// The directory integration settings of the `AccountService`
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccountServiceAuth {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub active_directory: Option<ExternalAccountProvider>,
+    pub id: String,
+    #[serde(rename = "LDAP")]
+    pub ldap: Option<ExternalAccountProvider>,
+    pub name: String,
+}
+
+/// The standard roles an account can be given. Vendors may define more, which
+/// `ManagerAccount::role_id` keeps as the raw string.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum RoleId {
+    Administrator,
+    Operator,
+    ReadOnly,
+}
+
+/// A local BMC user account
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ManagerAccount {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub enabled: Option<bool>,
+    pub id: String,
+    pub locked: Option<bool>,
+    pub name: String,
+    pub role_id: String,
+    pub user_name: String,
+}
+
+impl ManagerAccount {
+    /// The account's role, if it is one of the standard ones
+    pub fn role(&self) -> Option<RoleId> {
+        match self.role_id.as_str() {
+            "Administrator" => Some(RoleId::Administrator),
+            "Operator" => Some(RoleId::Operator),
+            "ReadOnly" => Some(RoleId::ReadOnly),
+            _ => None,
+        }
+    }
+}
+
+pub type ManagerAccountCollection = Collection;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NewAccount<'a> {
+    pub user_name: &'a str,
+    #[serde(serialize_with = "crate::secret::serialize_exposed")]
+    pub password: Secret,
+    pub role_id: RoleId,
+}
+
+#[test]
+fn test_account_service_auth_parser() {
+    let test_data = include_str!("../tests/account-service.json");
+    let result: AccountServiceAuth = serde_json::from_str(&test_data).unwrap();
+    let ldap = result.ldap.as_ref().unwrap();
+    assert!(ldap.is_configured());
+    assert_eq!(ldap.remote_role_mapping.len(), 2);
+    assert!(!result.active_directory.as_ref().unwrap().is_configured());
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_manager_account_parser() {
+    let test_data = include_str!("../tests/account.json");
+    let result: ManagerAccount = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.user_name, "provision");
+    assert_eq!(result.role(), Some(RoleId::Operator));
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_manager_account_collection_parser() {
+    let test_data = include_str!("../tests/accounts.json");
+    let result: ManagerAccountCollection = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.members.len(), 3);
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_new_account() {
+    let account = NewAccount {
+        user_name: "provision",
+        password: Secret::from("hunter2"),
+        role_id: RoleId::ReadOnly,
+    };
+    assert_eq!(
+        serde_json::to_value(&account).unwrap(),
+        serde_json::json!({ "UserName": "provision", "Password": "hunter2", "RoleId": "ReadOnly" })
+    );
+    assert!(!format!("{:?}", account).contains("hunter2"));
+}
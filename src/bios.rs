@@ -0,0 +1,563 @@
+use crate::common::*;
+use crate::error::RedfishError;
+use crate::manager::{FlowControl, SerialConsoleConfig};
+use crate::registry::AttributeRegistry;
+use crate::system::PowerOnDelay;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+
+/// A BIOS setting that is a simple on/off switch but is named differently by
+/// each vendor. Each entry is `(attribute name, enabled value, disabled value)`,
+/// and the value is checked against the host's attribute registry before it
+/// is staged, as firmware versions differ in the names they accept.
+#[derive(Debug, Clone, Copy)]
+pub struct BiosToggle {
+    pub description: &'static str,
+    pub attributes: &'static [(&'static str, &'static str, &'static str)],
+}
+
+/// Intel Hyper-Threading / AMD SMT
+pub const HYPERTHREADING: BiosToggle = BiosToggle {
+    description: "hyperthreading",
+    attributes: &[
+        // HPE
+        ("ProcHyperthreading", "Enabled", "Disabled"),
+        // Dell
+        ("LogicalProc", "Enabled", "Disabled"),
+        // Lenovo
+        ("Processors_HyperThreading", "Enable", "Disable"),
+    ],
+};
+
+/// Intel VT-x / AMD-V
+pub const VIRTUALIZATION: BiosToggle = BiosToggle {
+    description: "virtualization technology",
+    attributes: &[
+        // HPE and Dell
+        ("ProcVirtualization", "Enabled", "Disabled"),
+        // Lenovo
+        (
+            "Processors_IntelVirtualizationTechnology",
+            "Enable",
+            "Disable",
+        ),
+    ],
+};
+
+/// The fixed delays HPE offers through the `PowerOnDelay` attribute
+const HPE_POWER_ON_DELAYS: &[(&str, u32)] = &[
+    ("NoDelay", 0),
+    ("Delay15", 15),
+    ("Delay30", 30),
+    ("Delay45", 45),
+    ("Delay60", 60),
+];
+
+/// Baud rates HPE offers through `SerialConsoleBaudRate`, as `BaudRate<n>`
+const HPE_SERIAL_BAUD_RATES: &[u32] = &[9600, 19200, 38400, 57600, 115200];
+
+/// Baud rates Dell offers through `FailSafeBaud`
+const DELL_SERIAL_BAUD_RATES: &[u32] = &[9600, 19200, 57600, 115200];
+
+fn check_baud_rate(property: &str, bit_rate: u32, allowed: &[u32]) -> Result<(), RedfishError> {
+    if allowed.contains(&bit_rate) {
+        return Ok(());
+    }
+    Err(RedfishError::InvalidValue {
+        property: property.to_string(),
+        value: bit_rate.to_string(),
+        allowed: allowed.iter().map(|b| b.to_string()).collect(),
+    })
+}
+
+/// A BIOS attribute's current value together with its constraints from the
+/// attribute registry, enough to render it in a settings form
+#[derive(Debug, Clone, PartialEq)]
+pub struct BiosAttributeInfo {
+    pub name: String,
+    pub display_name: Option<String>,
+    pub current_value: Option<serde_json::Value>,
+    pub attribute_type: Option<String>,
+    /// The accepted values of an enumeration, empty for other types
+    pub allowed: Vec<String>,
+    pub lower_bound: Option<i64>,
+    pub upper_bound: Option<i64>,
+    pub read_only: bool,
+    pub reset_required: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Bios {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    #[serde(rename = "@Redfish.Settings")]
+    pub settings: Option<RedfishSettings>,
+    pub attribute_registry: Option<String>,
+    pub attributes: HashMap<String, serde_json::Value>,
+    pub id: String,
+    pub name: String,
+}
+
+impl Bios {
+    /// The raw value of a single attribute
+    pub fn attribute(&self, name: &str) -> Option<&serde_json::Value> {
+        self.attributes.get(name)
+    }
+
+    /// A single attribute converted to `T`, or `None` if it is missing or is
+    /// not representable as a `T`
+    pub fn attribute_as<T>(&self, name: &str) -> Option<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.attribute(name)
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
+
+    /// A single attribute converted to `T`, or `None` if it is missing. An
+    /// attribute that is present but not representable as a `T` is an error.
+    pub fn try_attribute_as<T>(&self, name: &str) -> Result<Option<T>, RedfishError>
+    where
+        T: DeserializeOwned,
+    {
+        let Some(v) = self.attribute(name) else {
+            return Ok(None);
+        };
+        serde_json::from_value(v.clone())
+            .map(Some)
+            .map_err(|_| RedfishError::InvalidValue {
+                property: name.to_string(),
+                value: v.to_string(),
+                allowed: Vec::new(),
+            })
+    }
+
+    /// The vendor attribute implementing `toggle` on this host, if any
+    pub fn find_toggle(
+        &self,
+        toggle: &BiosToggle,
+    ) -> Option<&'static (&'static str, &'static str, &'static str)> {
+        toggle
+            .attributes
+            .iter()
+            .find(|(name, _, _)| self.attributes.contains_key(*name))
+    }
+
+    /// The attribute to stage to set `toggle`, rejected unless `registry` lists
+    /// the value for it as allowed
+    pub fn toggle_attributes(
+        &self,
+        toggle: &BiosToggle,
+        enabled: bool,
+        registry: &AttributeRegistry,
+    ) -> Result<serde_json::Value, RedfishError> {
+        let (name, on, off) = self
+            .find_toggle(toggle)
+            .ok_or_else(|| RedfishError::NotSupported(toggle.description.to_string()))?;
+        let value = if enabled { on } else { off };
+        let allowed: Vec<String> = registry
+            .attribute(name)
+            .map(|entry| entry.value.iter().map(|v| v.value_name.clone()).collect())
+            .unwrap_or_default();
+        if !allowed.iter().any(|a| a == value) {
+            return Err(RedfishError::InvalidValue {
+                property: name.to_string(),
+                value: value.to_string(),
+                allowed,
+            });
+        }
+        Ok(serde_json::json!({ *name: value }))
+    }
+
+    /// The current state of `toggle`, or `None` if this host has no known
+    /// attribute for it or reports a value other than on/off
+    pub fn toggle_state(&self, toggle: &BiosToggle) -> Option<bool> {
+        let (name, on, off) = self.find_toggle(toggle)?;
+        match self.attributes.get(*name).and_then(|v| v.as_str()) {
+            Some(v) if v == *on => Some(true),
+            Some(v) if v == *off => Some(false),
+            _ => None,
+        }
+    }
+
+    /// The power-on delay set through a vendor attribute, HPE `PowerOnDelay` or
+    /// Dell `AcPwrRcvryDelay`
+    pub fn power_on_delay(&self) -> Option<PowerOnDelay> {
+        if let Some(value) = self.attribute_as::<String>("PowerOnDelay") {
+            if value == "Random" {
+                return Some(PowerOnDelay::Random);
+            }
+            return HPE_POWER_ON_DELAYS
+                .iter()
+                .find(|(name, _)| *name == value)
+                .map(|(_, seconds)| PowerOnDelay::Seconds(*seconds));
+        }
+        match self.attribute_as::<String>("AcPwrRcvryDelay").as_deref() {
+            Some("Immediate") => Some(PowerOnDelay::Seconds(0)),
+            Some("Random") => Some(PowerOnDelay::Random),
+            Some("User") => self
+                .attribute_as::<u32>("AcPwrRcvryUserDelay")
+                .map(PowerOnDelay::Seconds),
+            _ => None,
+        }
+    }
+
+    /// The vendor attributes to stage to set `delay`
+    pub fn power_on_delay_attributes(
+        &self,
+        delay: PowerOnDelay,
+    ) -> Result<serde_json::Value, RedfishError> {
+        if self.attributes.contains_key("PowerOnDelay") {
+            let value = match delay {
+                PowerOnDelay::Random => "Random",
+                PowerOnDelay::Seconds(seconds) => HPE_POWER_ON_DELAYS
+                    .iter()
+                    .find(|(_, s)| *s == seconds)
+                    .map(|(name, _)| *name)
+                    .ok_or_else(|| RedfishError::InvalidValue {
+                        property: "PowerOnDelay".to_string(),
+                        value: seconds.to_string(),
+                        allowed: HPE_POWER_ON_DELAYS
+                            .iter()
+                            .map(|(_, s)| s.to_string())
+                            .collect(),
+                    })?,
+            };
+            return Ok(serde_json::json!({ "PowerOnDelay": value }));
+        }
+        if self.attributes.contains_key("AcPwrRcvryDelay") {
+            return Ok(match delay {
+                PowerOnDelay::Random => serde_json::json!({ "AcPwrRcvryDelay": "Random" }),
+                PowerOnDelay::Seconds(0) => serde_json::json!({ "AcPwrRcvryDelay": "Immediate" }),
+                PowerOnDelay::Seconds(seconds) => serde_json::json!({
+                    "AcPwrRcvryDelay": "User",
+                    "AcPwrRcvryUserDelay": seconds,
+                }),
+            });
+        }
+        Err(RedfishError::NotSupported("power-on delay".to_string()))
+    }
+
+    /// Serial console redirection as set through vendor attributes, HPE
+    /// `SerialConsolePort`/`SerialConsoleBaudRate` or Dell `SerialComm`/`FailSafeBaud`.
+    /// Neither vendor exposes flow control here.
+    pub fn serial_console(&self) -> Option<SerialConsoleConfig> {
+        if let Some(rate) = self.attribute_as::<String>("SerialConsoleBaudRate") {
+            return Some(SerialConsoleConfig {
+                enabled: self
+                    .attribute_as::<String>("SerialConsolePort")
+                    .map(|port| port != "Disabled"),
+                bit_rate: rate.strip_prefix("BaudRate").and_then(|r| r.parse().ok()),
+                flow_control: None,
+            });
+        }
+        if let Some(comm) = self.attribute_as::<String>("SerialComm") {
+            return Some(SerialConsoleConfig {
+                enabled: Some(comm.starts_with("OnConRedir")),
+                bit_rate: self
+                    .attribute_as::<String>("FailSafeBaud")
+                    .and_then(|b| b.parse().ok()),
+                flow_control: None,
+            });
+        }
+        None
+    }
+
+    /// The vendor attributes to stage to set serial console redirection
+    pub fn serial_console_attributes(
+        &self,
+        enabled: bool,
+        bit_rate: u32,
+        flow_control: FlowControl,
+    ) -> Result<serde_json::Value, RedfishError> {
+        if flow_control != FlowControl::None {
+            return Err(RedfishError::InvalidValue {
+                property: "FlowControl".to_string(),
+                value: format!("{:?}", flow_control),
+                allowed: vec!["None".to_string()],
+            });
+        }
+        if self.attributes.contains_key("SerialConsoleBaudRate") {
+            check_baud_rate("SerialConsoleBaudRate", bit_rate, HPE_SERIAL_BAUD_RATES)?;
+            let port = match self.attribute_as::<String>("SerialConsolePort") {
+                _ if !enabled => "Disabled".to_string(),
+                Some(port) if port != "Disabled" => port,
+                _ => "Virtual".to_string(),
+            };
+            return Ok(serde_json::json!({
+                "SerialConsoleBaudRate": format!("BaudRate{}", bit_rate),
+                "SerialConsolePort": port,
+            }));
+        }
+        if self.attributes.contains_key("SerialComm") {
+            check_baud_rate("FailSafeBaud", bit_rate, DELL_SERIAL_BAUD_RATES)?;
+            return Ok(serde_json::json!({
+                "SerialComm": if enabled { "OnConRedir" } else { "Off" },
+                "FailSafeBaud": bit_rate.to_string(),
+            }));
+        }
+        Err(RedfishError::NotSupported(
+            "serial console redirection".to_string(),
+        ))
+    }
+
+    /// Merge the current attribute values with the constraints in `registry`.
+    /// Attributes come in registry order, followed by any the registry does not
+    /// describe, which are treated as writable and untyped.
+    pub fn attribute_schema(&self, registry: &AttributeRegistry) -> Vec<BiosAttributeInfo> {
+        let mut schema: Vec<BiosAttributeInfo> = registry
+            .registry_entries
+            .attributes
+            .iter()
+            .map(|entry| BiosAttributeInfo {
+                name: entry.attribute_name.clone(),
+                display_name: entry.display_name.clone(),
+                current_value: self.attributes.get(&entry.attribute_name).cloned(),
+                attribute_type: entry.attribute_type.clone(),
+                allowed: entry.value.iter().map(|v| v.value_name.clone()).collect(),
+                lower_bound: entry.lower_bound,
+                upper_bound: entry.upper_bound,
+                read_only: entry.read_only.unwrap_or(false),
+                reset_required: entry.reset_required.unwrap_or(true),
+            })
+            .collect();
+        let mut undescribed: Vec<&String> = self
+            .attributes
+            .keys()
+            .filter(|name| registry.attribute(name).is_none())
+            .collect();
+        undescribed.sort();
+        schema.extend(undescribed.into_iter().map(|name| BiosAttributeInfo {
+            name: name.clone(),
+            display_name: None,
+            current_value: self.attributes.get(name).cloned(),
+            attribute_type: None,
+            allowed: Vec::new(),
+            lower_bound: None,
+            upper_bound: None,
+            read_only: false,
+            reset_required: true,
+        }));
+        schema
+    }
+
+    /// The resource pending BIOS changes are staged on, relative to the service root
+    pub fn settings_path(&self) -> String {
+        match &self.settings {
+            Some(s) => s.settings_object.path().to_string(),
+            None => format!("{}Settings/", self.odata_path()),
+        }
+    }
+
+    fn odata_path(&self) -> String {
+        let path = relative_path(&self.odata.odata_id);
+        if path.ends_with('/') {
+            path.to_string()
+        } else {
+            format!("{}/", path)
+        }
+    }
+}
+
+#[test]
+fn test_bios_parser() {
+    let test_data = include_str!("../tests/bios.json");
+    let result: Bios = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+    assert_eq!(result.settings_path(), "Systems/1/Bios/Settings/");
+}
+
+#[test]
+fn test_bios_toggles() {
+    let test_data = include_str!("../tests/bios.json");
+    let mut bios: Bios = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(bios.toggle_state(&HYPERTHREADING), Some(true));
+    assert_eq!(bios.toggle_state(&VIRTUALIZATION), Some(false));
+
+    bios.attributes.remove("ProcHyperthreading");
+    bios.attributes
+        .insert("LogicalProc".to_string(), serde_json::json!("Disabled"));
+    assert_eq!(bios.toggle_state(&HYPERTHREADING), Some(false));
+
+    bios.attributes.remove("LogicalProc");
+    assert_eq!(bios.toggle_state(&HYPERTHREADING), None);
+}
+
+#[test]
+fn test_bios_toggle_attributes() {
+    let mut bios: Bios = serde_json::from_str(include_str!("../tests/bios.json")).unwrap();
+    let registry: AttributeRegistry =
+        serde_json::from_str(include_str!("../tests/bios-attribute-registry.json")).unwrap();
+    assert_eq!(
+        bios.toggle_attributes(&HYPERTHREADING, false, &registry)
+            .unwrap(),
+        serde_json::json!({ "ProcHyperthreading": "Disabled" })
+    );
+    // The registry does not describe ProcVirtualization, so nothing is allowed
+    assert!(matches!(
+        bios.toggle_attributes(&VIRTUALIZATION, true, &registry),
+        Err(RedfishError::InvalidValue { allowed, .. }) if allowed.is_empty()
+    ));
+
+    // A registry that names the values differently from the toggle table
+    let mut registry = registry;
+    for entry in &mut registry.registry_entries.attributes {
+        if entry.attribute_name == "ProcHyperthreading" {
+            for v in &mut entry.value {
+                v.value_name = v.value_name.trim_end_matches('d').to_string();
+            }
+        }
+    }
+    assert!(matches!(
+        bios.toggle_attributes(&HYPERTHREADING, true, &registry),
+        Err(RedfishError::InvalidValue { value, allowed, .. })
+            if value == "Enabled" && allowed == ["Enable", "Disable"]
+    ));
+
+    bios.attributes.remove("ProcHyperthreading");
+    assert!(matches!(
+        bios.toggle_attributes(&HYPERTHREADING, true, &registry),
+        Err(RedfishError::NotSupported(_))
+    ));
+}
+
+#[test]
+fn test_bios_attribute_accessors() {
+    let test_data = include_str!("../tests/bios.json");
+    let bios: Bios = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(bios.attribute("BootMode"), Some(&serde_json::json!("Uefi")));
+    assert_eq!(
+        bios.attribute_as::<String>("WorkloadProfile").as_deref(),
+        Some("GeneralPowerEfficientCompute")
+    );
+    assert_eq!(bios.attribute_as::<u32>("BootMode"), None);
+    assert_eq!(bios.attribute_as::<String>("NoSuchAttribute"), None);
+}
+
+#[test]
+fn test_bios_try_attribute_as() {
+    let test_data = include_str!("../tests/bios.json");
+    let bios: Bios = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(
+        bios.try_attribute_as::<String>("BootMode")
+            .unwrap()
+            .as_deref(),
+        Some("Uefi")
+    );
+    assert_eq!(
+        bios.try_attribute_as::<String>("NoSuchAttribute").unwrap(),
+        None
+    );
+    match bios.try_attribute_as::<u32>("BootMode") {
+        Err(RedfishError::InvalidValue {
+            property, value, ..
+        }) => {
+            assert_eq!(property, "BootMode");
+            assert_eq!(value, "\"Uefi\"");
+        }
+        other => panic!("expected InvalidValue, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_bios_power_on_delay() {
+    let test_data = include_str!("../tests/bios.json");
+    let mut bios: Bios = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(bios.power_on_delay(), Some(PowerOnDelay::Seconds(0)));
+    assert_eq!(
+        bios.power_on_delay_attributes(PowerOnDelay::Seconds(30))
+            .unwrap(),
+        serde_json::json!({ "PowerOnDelay": "Delay30" })
+    );
+    assert!(matches!(
+        bios.power_on_delay_attributes(PowerOnDelay::Seconds(20)),
+        Err(RedfishError::InvalidValue { .. })
+    ));
+
+    bios.attributes.remove("PowerOnDelay");
+    bios.attributes
+        .insert("AcPwrRcvryDelay".to_string(), serde_json::json!("User"));
+    bios.attributes
+        .insert("AcPwrRcvryUserDelay".to_string(), serde_json::json!(120));
+    assert_eq!(bios.power_on_delay(), Some(PowerOnDelay::Seconds(120)));
+    assert_eq!(
+        bios.power_on_delay_attributes(PowerOnDelay::Random)
+            .unwrap(),
+        serde_json::json!({ "AcPwrRcvryDelay": "Random" })
+    );
+
+    bios.attributes.remove("AcPwrRcvryDelay");
+    assert_eq!(bios.power_on_delay(), None);
+    assert!(matches!(
+        bios.power_on_delay_attributes(PowerOnDelay::Random),
+        Err(RedfishError::NotSupported(_))
+    ));
+}
+
+#[test]
+fn test_bios_serial_console() {
+    let test_data = include_str!("../tests/bios.json");
+    let mut bios: Bios = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(
+        bios.serial_console(),
+        Some(SerialConsoleConfig {
+            enabled: Some(true),
+            bit_rate: Some(115200),
+            flow_control: None,
+        })
+    );
+    assert_eq!(
+        bios.serial_console_attributes(true, 57600, FlowControl::None)
+            .unwrap(),
+        serde_json::json!({
+            "SerialConsoleBaudRate": "BaudRate57600",
+            "SerialConsolePort": "Virtual",
+        })
+    );
+    assert!(matches!(
+        bios.serial_console_attributes(true, 14400, FlowControl::None),
+        Err(RedfishError::InvalidValue { .. })
+    ));
+    assert!(matches!(
+        bios.serial_console_attributes(true, 115200, FlowControl::Hardware),
+        Err(RedfishError::InvalidValue { .. })
+    ));
+
+    bios.attributes.remove("SerialConsoleBaudRate");
+    bios.attributes
+        .insert("SerialComm".to_string(), serde_json::json!("Off"));
+    assert_eq!(bios.serial_console().unwrap().enabled, Some(false));
+    assert_eq!(
+        bios.serial_console_attributes(true, 115200, FlowControl::None)
+            .unwrap(),
+        serde_json::json!({ "SerialComm": "OnConRedir", "FailSafeBaud": "115200" })
+    );
+}
+
+#[test]
+fn test_bios_attribute_schema() {
+    let bios: Bios = serde_json::from_str(include_str!("../tests/bios.json")).unwrap();
+    let registry: AttributeRegistry =
+        serde_json::from_str(include_str!("../tests/bios-attribute-registry.json")).unwrap();
+    let schema = bios.attribute_schema(&registry);
+    assert_eq!(schema.len(), bios.attributes.len() + 1);
+
+    let boot_mode = &schema[1];
+    assert_eq!(boot_mode.name, "BootMode");
+    assert_eq!(boot_mode.current_value, Some(serde_json::json!("Uefi")));
+    assert_eq!(boot_mode.allowed, vec!["Uefi", "LegacyBios"]);
+    assert!(boot_mode.reset_required);
+
+    let thermal = schema.iter().find(|a| a.name == "ThermalConfig").unwrap();
+    assert!(thermal.read_only);
+
+    let unreported = schema
+        .iter()
+        .find(|a| a.name == "UnreportedSetting")
+        .unwrap();
+    assert_eq!(unreported.current_value, None);
+
+    // Attributes the registry does not describe follow, sorted by name
+    assert_eq!(schema[5].name, "AutoPowerOn");
+}
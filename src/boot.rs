@@ -0,0 +1,86 @@
+/// A device to boot from instead of the normal boot order
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum BootSource {
+    Pxe,
+    Hdd,
+    Cd,
+    Usb,
+    BiosSetup,
+}
+
+/// How long a boot source override lasts
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum BootEnabled {
+    /// Only the next boot, after which the override reverts to `Disabled`
+    Once,
+    Continuous,
+    Disabled,
+}
+
+/// The `Boot` object of a computer system. Values are kept as the firmware
+/// reports them, since vendors add targets beyond those in `BootSource`.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct BootOptions {
+    pub boot_source_override_enabled: Option<String>,
+    /// `UEFI` or `Legacy`
+    pub boot_source_override_mode: Option<String>,
+    pub boot_source_override_target: Option<String>,
+    #[serde(rename = "BootSourceOverrideTarget@Redfish.AllowableValues", default)]
+    pub boot_source_override_target_allowable_values: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SystemBoot {
+    pub boot: BootOptions,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct BootOverride {
+    pub boot_source_override_enabled: BootEnabled,
+    pub boot_source_override_target: BootSource,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SetBoot {
+    pub boot: BootOverride,
+}
+
+#[test]
+fn test_boot_options_parser() {
+    let test_data = include_str!("../tests/system.json");
+    let result: SystemBoot = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(
+        result.boot.boot_source_override_target.as_deref(),
+        Some("None")
+    );
+    assert!(
+        result
+            .boot
+            .boot_source_override_target_allowable_values
+            .contains(&"Pxe".to_string())
+    );
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_boot_override_body() {
+    let body = SetBoot {
+        boot: BootOverride {
+            boot_source_override_enabled: BootEnabled::Once,
+            boot_source_override_target: BootSource::Pxe,
+        },
+    };
+    assert_eq!(
+        serde_json::to_value(&body).unwrap(),
+        serde_json::json!({
+            "Boot": {
+                "BootSourceOverrideEnabled": "Once",
+                "BootSourceOverrideTarget": "Pxe",
+            }
+        })
+    );
+}
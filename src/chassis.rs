@@ -0,0 +1,82 @@
+use crate::common::*;
+
+/// The states of a chassis's or drive's identify LED, as set through `IndicatorLED`
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum IndicatorLed {
+    Lit,
+    Blinking,
+    Off,
+}
+
+impl IndicatorLed {
+    /// Parse an `IndicatorLED` value, `None` for `Unknown` or vendor values
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match value {
+            "Lit" => Some(IndicatorLed::Lit),
+            "Blinking" => Some(IndicatorLed::Blinking),
+            "Off" => Some(IndicatorLed::Off),
+            _ => None,
+        }
+    }
+}
+
+/// The physical enclosure of a system, with the details printed on its label
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Chassis {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub chassis_type: String,
+    pub id: String,
+    #[serde(rename = "IndicatorLED")]
+    pub indicator_led: Option<String>,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub name: String,
+    pub part_number: Option<String>,
+    pub serial_number: Option<String>,
+    pub status: Option<SomeStatus>,
+}
+
+impl Chassis {
+    /// The identify LED state, if the chassis reports one of the standard ones
+    pub fn indicator_led(&self) -> Option<IndicatorLed> {
+        self.indicator_led.as_deref().and_then(IndicatorLed::parse)
+    }
+}
+
+pub type ChassisCollection = Collection;
+
+#[derive(Debug, Serialize)]
+pub struct SetIndicatorLed {
+    #[serde(rename = "IndicatorLED")]
+    pub indicator_led: IndicatorLed,
+}
+
+#[test]
+fn test_chassis_parser() {
+    let test_data = include_str!("../tests/chassis.json");
+    let result: Chassis = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.model.as_deref(), Some("PowerEdge R650"));
+    assert_eq!(result.indicator_led(), Some(IndicatorLed::Off));
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_chassis_collection_parser() {
+    let test_data = include_str!("../tests/chassis-collection.json");
+    let result: ChassisCollection = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.members.len(), 2);
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_set_indicator_led() {
+    let body = SetIndicatorLed {
+        indicator_led: IndicatorLed::Blinking,
+    };
+    assert_eq!(
+        serde_json::to_value(&body).unwrap(),
+        serde_json::json!({ "IndicatorLED": "Blinking" })
+    );
+}
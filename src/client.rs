@@ -0,0 +1,99 @@
+use crate::{Redfish, RedfishError};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// The requests a Redfish client can make, so helpers written against this
+/// trait work with `Redfish` and with stand-ins such as test doubles
+pub trait RedfishClient {
+    fn get<T>(&self, api: &str) -> Result<T, RedfishError>
+    where
+        T: DeserializeOwned + ::std::fmt::Debug;
+
+    fn post<B, T>(&self, api: &str, body: &B) -> Result<T, RedfishError>
+    where
+        B: Serialize,
+        T: DeserializeOwned + ::std::fmt::Debug;
+
+    fn patch<B, T>(&self, api: &str, body: &B) -> Result<T, RedfishError>
+    where
+        B: Serialize,
+        T: DeserializeOwned + ::std::fmt::Debug;
+
+    fn delete(&self, api: &str) -> Result<(), RedfishError>;
+}
+
+impl RedfishClient for Redfish {
+    fn get<T>(&self, api: &str) -> Result<T, RedfishError>
+    where
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        Redfish::get(self, api)
+    }
+
+    fn post<B, T>(&self, api: &str, body: &B) -> Result<T, RedfishError>
+    where
+        B: Serialize,
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        Redfish::post(self, api, body)
+    }
+
+    fn patch<B, T>(&self, api: &str, body: &B) -> Result<T, RedfishError>
+    where
+        B: Serialize,
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        Redfish::patch(self, api, body)
+    }
+
+    fn delete(&self, api: &str) -> Result<(), RedfishError> {
+        Redfish::delete(self, api)
+    }
+}
+
+#[cfg(test)]
+struct Fixture(&'static str);
+
+#[cfg(test)]
+impl RedfishClient for Fixture {
+    fn get<T>(&self, api: &str) -> Result<T, RedfishError>
+    where
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        serde_json::from_str(self.0).map_err(|source| RedfishError::Deserialize {
+            endpoint: api.to_string(),
+            body: crate::error::snippet(self.0.as_bytes()),
+            source,
+        })
+    }
+
+    fn post<B, T>(&self, api: &str, _body: &B) -> Result<T, RedfishError>
+    where
+        B: Serialize,
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        self.get(api)
+    }
+
+    fn patch<B, T>(&self, api: &str, _body: &B) -> Result<T, RedfishError>
+    where
+        B: Serialize,
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        self.get(api)
+    }
+
+    fn delete(&self, _api: &str) -> Result<(), RedfishError> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_generic_client() {
+    fn task_finished<C: RedfishClient>(client: &C) -> Result<bool, RedfishError> {
+        let t: crate::task::Task = client.get("TaskService/Tasks/JID_467696020275/")?;
+        Ok(t.task_state.is_finished())
+    }
+    let client = Fixture(include_str!("../tests/task.json"));
+    assert!(task_finished(&client).unwrap());
+}
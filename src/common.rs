@@ -1,26 +1,26 @@
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct FirmwareCurrent {
     #[serde(rename = "VersionString")]
     pub version: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Firmware {
     pub current: FirmwareCurrent,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Href {
     pub href: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ExtRef {
     pub extref: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged, rename_all = "PascalCase")]
 pub enum LinkType {
     SelfLink {
@@ -75,10 +75,12 @@ pub enum LinkType {
     },
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ODataLinks {
     #[serde(rename = "@odata.context")]
     pub odata_context: String,
+    #[serde(rename = "@odata.etag")]
+    pub odata_etag: Option<String>,
     #[serde(rename = "@odata.id")]
     pub odata_id: String,
     #[serde(rename = "@odata.type")]
@@ -87,13 +89,124 @@ pub struct ODataLinks {
     pub links: LinkType,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ODataId {
     #[serde(rename = "@odata.id")]
     pub odata_id: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+impl ODataId {
+    /// The link as a path relative to the service root, suitable for `Redfish::get`
+    pub fn path(&self) -> &str {
+        relative_path(&self.odata_id)
+    }
+}
+
+/// Parse a Redfish `DateTime` property, which is ISO 8601 with an offset
+#[cfg(feature = "time")]
+pub fn parse_timestamp(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|t| t.with_timezone(&chrono::Utc))
+}
+
+/// A link to another resource, as found in a collection's `Members`
+pub type OdataLink = ODataId;
+
+/// Any OData collection, or one page of it, for walking members whose IDs are
+/// not known ahead of time
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Collection {
+    #[serde(rename = "Members", default)]
+    pub members: Vec<OdataLink>,
+    #[serde(rename = "Members@odata.count")]
+    pub members_count: Option<u64>,
+    #[serde(rename = "Members@odata.nextLink")]
+    pub next_link: Option<String>,
+    #[serde(rename = "@odata.etag")]
+    pub odata_etag: Option<String>,
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+}
+
+#[test]
+fn test_collection_parser() {
+    let test_data = include_str!("../tests/resource-blocks.json");
+    let result: Collection = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.members_count, Some(result.members.len() as u64));
+    assert!(result.next_link.is_none());
+    println!("result: {:#?}", result);
+}
+
+/// Turn an `@odata.id` into a path relative to the service root. Links may be
+/// absolute (`/redfish/v1/Systems/1`), carry the prefix without the leading
+/// slash (`redfish/v2/Systems/1`) or already be relative (`Systems/1`); any
+/// leading `/` and `redfish/vN/` prefix is stripped and everything else is
+/// left alone.
+pub fn relative_path(odata_id: &str) -> &str {
+    let path = odata_id.trim_start_matches('/');
+    let rest = match path.strip_prefix("redfish/") {
+        Some(rest) => rest,
+        None => return path,
+    };
+    let (version, remainder) = rest.split_once('/').unwrap_or((rest, ""));
+    let is_version = version.len() > 1
+        && version.starts_with('v')
+        && version[1..].chars().all(|c| c.is_ascii_digit());
+    if is_version { remainder } else { path }
+}
+
+#[test]
+fn test_relative_path() {
+    assert_eq!(relative_path("/redfish/v1/Systems/1"), "Systems/1");
+    assert_eq!(relative_path("/redfish/v1/Systems/1/"), "Systems/1/");
+    assert_eq!(relative_path("redfish/v1/Systems/1"), "Systems/1");
+    assert_eq!(relative_path("/redfish/v2/Chassis/1/Power/"), "Chassis/1/Power/");
+    assert_eq!(relative_path("Systems/1"), "Systems/1");
+    assert_eq!(relative_path("/Systems/1"), "Systems/1");
+    assert_eq!(relative_path("/redfish/v1/"), "");
+    assert_eq!(relative_path("/redfish/v1"), "");
+    // Only a real version segment is stripped
+    assert_eq!(relative_path("/redfish/vendor/Systems/1"), "redfish/vendor/Systems/1");
+}
+
+/// The OData annotations carried by standard (non-HP) Redfish resources
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ODataResource {
+    #[serde(rename = "@odata.context")]
+    pub odata_context: Option<String>,
+    /// The ETag of the resource as carried in the body, which some BMCs send
+    /// instead of the `ETag` header
+    #[serde(rename = "@odata.etag")]
+    pub odata_etag: Option<String>,
+    #[serde(rename = "@odata.id")]
+    pub odata_id: String,
+    #[serde(rename = "@odata.type")]
+    pub odata_type: String,
+}
+
+/// An inline excerpt of a `Sensor` resource
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SensorExcerpt {
+    pub data_source_uri: Option<String>,
+    pub reading: Option<f64>,
+}
+
+/// The `@Redfish.Settings` annotation pointing at the resource where changes
+/// to a resource are staged until they are applied
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RedfishSettings {
+    #[serde(rename = "ETag")]
+    pub etag: Option<String>,
+    pub settings_object: ODataId,
+    #[serde(default)]
+    pub supported_apply_times: Vec<String>,
+    pub time: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ODataContext {
     #[serde(rename = "@odata.context")]
     pub odata_context: String,
@@ -101,21 +214,126 @@ pub struct ODataContext {
     pub links: LinkType,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+/// The `Health` of a resource's `Status`.
+///
+/// Newer schema versions and vendors add values to Redfish enums, so the enums
+/// this crate parses from a service end in a `#[serde(other)]` catch-all, here
+/// `Unknown`, instead of rejecting the whole resource.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Health {
+    #[serde(rename = "OK")]
+    Ok,
+    Warning,
+    Critical,
+    #[serde(other)]
+    Unknown,
+}
+
+impl Health {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Health::Ok => "OK",
+            Health::Warning => "Warning",
+            Health::Critical => "Critical",
+            Health::Unknown => "Unknown",
+        }
+    }
+}
+
+/// The `State` of a resource's `Status`, e.g. whether it is enabled, absent
+/// or still starting
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Enabled,
+    Disabled,
+    Absent,
+    StandbyOffline,
+    StandbySpare,
+    InTest,
+    Starting,
+    UnavailableOffline,
+    Deferring,
+    Quiesced,
+    Updating,
+    Qualified,
+    #[serde(other)]
+    Unknown,
+}
+
+impl State {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            State::Enabled => "Enabled",
+            State::Disabled => "Disabled",
+            State::Absent => "Absent",
+            State::StandbyOffline => "StandbyOffline",
+            State::StandbySpare => "StandbySpare",
+            State::InTest => "InTest",
+            State::Starting => "Starting",
+            State::UnavailableOffline => "UnavailableOffline",
+            State::Deferring => "Deferring",
+            State::Quiesced => "Quiesced",
+            State::Updating => "Updating",
+            State::Qualified => "Qualified",
+            State::Unknown => "Unknown",
+        }
+    }
+}
+
+/// A resource's `Status`, with health and state parsed into enums
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResourceStatus {
+    pub health: Option<Health>,
+    /// The worst health of the resource and everything it contains
+    pub health_rollup: Option<Health>,
+    pub state: Option<State>,
+}
+
+impl ResourceStatus {
+    /// Whether the resource reports `OK`, or no health at all, which is how
+    /// firmware commonly describes components it does not monitor
+    pub fn is_healthy(&self) -> bool {
+        matches!(self.health, None | Some(Health::Ok))
+    }
+}
+
+#[test]
+fn test_resource_status() {
+    let status: ResourceStatus =
+        serde_json::from_value(serde_json::json!({ "Health": "OK", "State": "Enabled" })).unwrap();
+    assert!(status.is_healthy());
+    assert_eq!(status.state, Some(State::Enabled));
+
+    let status: ResourceStatus =
+        serde_json::from_value(serde_json::json!({ "Health": "Critical", "State": "Absent" }))
+            .unwrap();
+    assert!(!status.is_healthy());
+    assert_eq!(status.state, Some(State::Absent));
+
+    let status: ResourceStatus =
+        serde_json::from_value(serde_json::json!({ "Health": null, "State": "Degraded" })).unwrap();
+    assert!(status.is_healthy());
+    assert_eq!(status.state, Some(State::Unknown));
+    assert_eq!(status.health(), "OK");
+    assert_eq!(status.state(), "Unknown");
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct AllStatus {
     pub health: String,
     pub state: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct SomeStatus {
     pub health: Option<String>,
     pub state: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct HpType {
     #[serde(rename = "@odata.type")]
@@ -124,6 +342,74 @@ pub struct HpType {
     pub hp_type: String,
 }
 
+/// The ETag to send as `If-Match` for a resource, preferring the `ETag`
+/// response header and falling back to the inline `@odata.etag`
+pub fn resource_etag(header: Option<&str>, resource: &serde_json::Value) -> Option<String> {
+    header
+        .filter(|h| !h.is_empty())
+        .or_else(|| resource.get("@odata.etag").and_then(|e| e.as_str()))
+        .map(|e| e.to_string())
+}
+
+#[test]
+fn test_resource_etag() {
+    let resource = serde_json::json!({ "@odata.etag": "W/\"1\"", "Id": "1" });
+    assert_eq!(
+        resource_etag(Some("\"2\""), &resource).as_deref(),
+        Some("\"2\"")
+    );
+    assert_eq!(resource_etag(None, &resource).as_deref(), Some("W/\"1\""));
+    assert_eq!(
+        resource_etag(Some(""), &resource).as_deref(),
+        Some("W/\"1\"")
+    );
+    assert_eq!(resource_etag(None, &serde_json::json!({})), None);
+}
+
+/// Check whether a resource advertises `action` (e.g. `VirtualMedia.InsertMedia`,
+/// with or without the leading `#`) in its `Actions` object, including any
+/// vendor actions nested under `Actions.Oem`
+pub fn has_action(resource: &serde_json::Value, action: &str) -> bool {
+    let key = format!("#{}", action.trim_start_matches('#'));
+    let actions = match resource.get("Actions").and_then(|a| a.as_object()) {
+        Some(a) => a,
+        None => return false,
+    };
+    if actions.contains_key(&key) {
+        return true;
+    }
+    match actions.get("Oem").and_then(|o| o.as_object()) {
+        Some(oem) => oem
+            .values()
+            .filter_map(|vendor| vendor.as_object())
+            .any(|vendor| vendor.contains_key(&key)),
+        None => false,
+    }
+}
+
+#[test]
+fn test_has_action() {
+    let test_data = include_str!("../tests/manager.json");
+    let manager: serde_json::Value = serde_json::from_str(&test_data).unwrap();
+    assert!(has_action(&manager, "Manager.Reset"));
+    assert!(has_action(&manager, "#Manager.Reset"));
+    assert!(!has_action(&manager, "VirtualMedia.InsertMedia"));
+
+    let oem = serde_json::json!({
+        "Actions": {
+            "Oem": {
+                "Hp": {
+                    "#HpiLO.ResetToFactoryDefaults": {
+                        "target": "/redfish/v1/Managers/1/Actions/Oem/Hp/HpiLO.ResetToFactoryDefaults/"
+                    }
+                }
+            }
+        }
+    });
+    assert!(has_action(&oem, "HpiLO.ResetToFactoryDefaults"));
+    assert!(!has_action(&serde_json::json!({"Id": "1"}), "Manager.Reset"));
+}
+
 pub trait Status {
     fn health(&self) -> String;
     fn state(&self) -> String;
@@ -141,6 +427,15 @@ impl Status for SomeStatus {
     }
 }
 
+impl Status for ResourceStatus {
+    fn health(&self) -> String {
+        self.health.unwrap_or(Health::Ok).as_str().to_string()
+    }
+    fn state(&self) -> String {
+        self.state.unwrap_or(State::Unknown).as_str().to_string()
+    }
+}
+
 impl Status for AllStatus {
     fn health(&self) -> String {
         self.health.clone()
@@ -150,6 +445,72 @@ impl Status for AllStatus {
     }
 }
 
+/// A background operation a resource reports in its `Operations` array, e.g.
+/// a drive being erased or a volume being initialized
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Operation {
+    pub associated_task: Option<ODataId>,
+    pub operation_name: Option<String>,
+    pub percentage_complete: Option<u32>,
+}
+
+pub trait Operations {
+    fn operations(&self) -> &[Operation];
+
+    /// The operations that have not finished, as `(name, percent complete)`
+    fn in_progress_operations(&self) -> Vec<(String, Option<u32>)> {
+        self.operations()
+            .iter()
+            .filter(|op| op.percentage_complete.is_none_or(|p| p < 100))
+            .map(|op| {
+                let name = op.operation_name.as_deref().unwrap_or("Unknown");
+                (name.to_string(), op.percentage_complete)
+            })
+            .collect()
+    }
+}
+
 pub trait StatusVec {
     fn get_vec(&self) -> Vec<Box<dyn Status>>;
 }
+
+/// The power, thermal and manager status of a host fetched together by
+/// `Redfish::get_overview`. A part the host failed to return is `None`, but
+/// when all three requests fail `get_overview` returns the power error instead.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Overview {
+    pub power: Option<crate::power::Power>,
+    pub thermal: Option<crate::thermal::Thermal>,
+    pub manager: Option<crate::manager::Manager>,
+}
+
+#[test]
+fn test_overview_roundtrip() {
+    let overview = Overview {
+        power: serde_json::from_str(include_str!("../tests/power.json")).unwrap(),
+        thermal: None,
+        manager: serde_json::from_str(include_str!("../tests/manager.json")).unwrap(),
+    };
+    let cached = serde_json::to_string(&overview).unwrap();
+    let reloaded: Overview = serde_json::from_str(&cached).unwrap();
+    assert!(reloaded.power.is_some());
+    assert!(reloaded.thermal.is_none());
+    assert!(reloaded.manager.is_some());
+}
+
+/// Check a fixture parsed as `T` serializes to JSON that parses back to the
+/// same thing, so the parsed form can be cached and reloaded
+#[cfg(test)]
+pub(crate) fn assert_roundtrip<T>(test_data: &str)
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let parsed: T = serde_json::from_str(test_data).unwrap();
+    let cached = serde_json::to_string(&parsed).unwrap();
+    let reloaded: T = serde_json::from_str(&cached).unwrap();
+    assert_eq!(
+        serde_json::to_value(&parsed).unwrap(),
+        serde_json::to_value(&reloaded).unwrap()
+    );
+}
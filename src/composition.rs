@@ -0,0 +1,69 @@
+use crate::common::*;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CompositionStatus {
+    /// `Unused`, `Composing`, `ComposedAndAvailable`, `Composed`, `Unavailable` or `Failed`
+    pub composition_state: String,
+    pub max_compositions: Option<i64>,
+    pub number_of_compositions: Option<i64>,
+    pub reserved: Option<bool>,
+    pub sharing_capable: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResourceBlockLinks {
+    #[serde(default)]
+    pub chassis: Vec<ODataId>,
+    #[serde(default)]
+    pub computer_systems: Vec<ODataId>,
+    #[serde(default)]
+    pub zones: Vec<ODataId>,
+}
+
+/// A block of composable resources, such as compute, memory or drives, that
+/// can be assembled into a composed system
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResourceBlock {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub composition_status: CompositionStatus,
+    #[serde(default)]
+    pub computer_systems: Vec<ODataId>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub ethernet_interfaces: Vec<ODataId>,
+    pub id: String,
+    pub links: Option<ResourceBlockLinks>,
+    #[serde(default)]
+    pub memory: Vec<ODataId>,
+    pub name: String,
+    #[serde(default)]
+    pub processors: Vec<ODataId>,
+    /// e.g. `Compute`, `Processor`, `Memory`, `Network`, `Storage`
+    #[serde(default)]
+    pub resource_block_type: Vec<String>,
+    #[serde(default)]
+    pub simple_storage: Vec<ODataId>,
+    pub status: Option<SomeStatus>,
+    #[serde(default)]
+    pub storage: Vec<ODataId>,
+}
+
+#[test]
+fn test_resource_block_parser() {
+    let test_data = include_str!("../tests/resource-block.json");
+    let result: ResourceBlock = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
+
+pub type ResourceBlocks = Collection;
+
+#[test]
+fn test_resource_blocks_parser() {
+    let test_data = include_str!("../tests/resource-blocks.json");
+    let result: ResourceBlocks = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
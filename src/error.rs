@@ -0,0 +1,395 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum RedfishError {
+    Http(reqwest::Error),
+    /// The request was sent but no answer came within `Config::timeout`.
+    /// Returned instead of `ReadTimeout` when a timeout is configured; a
+    /// connection that could not be established stays `ConnectTimeout`. Also
+    /// returned, without a `source`, when waiting on a task gives up.
+    Timeout {
+        after: std::time::Duration,
+        source: Option<reqwest::Error>,
+    },
+    /// The TCP connection to the BMC was not established in time, usually
+    /// meaning the host is down or unreachable
+    ConnectTimeout(reqwest::Error),
+    /// The BMC accepted the connection but was too slow to answer
+    ReadTimeout(reqwest::Error),
+    /// The TLS handshake failed, e.g. on an untrusted or expired certificate
+    TlsError(reqwest::Error),
+    /// The BMC's host name could not be resolved
+    Dns(reqwest::Error),
+    /// The BMC's address actively refused the connection
+    ConnectionRefused(reqwest::Error),
+    /// The response from `endpoint` did not have the shape the caller asked
+    /// for. `body` holds the start of the response, to see what was sent instead.
+    Deserialize {
+        endpoint: String,
+        body: String,
+        source: serde_json::Error,
+    },
+    /// The server answered with a non-success status
+    UnexpectedStatus {
+        status: u16,
+        body: String,
+    },
+    /// The resource changed since the ETag sent in `If-Match` was read. Read
+    /// it again and retry.
+    PreconditionFailed {
+        body: String,
+    },
+    /// The session was rejected, and so was the request retried after
+    /// logging in again
+    Unauthorized {
+        body: String,
+    },
+    /// Following a collection's `Members@odata.nextLink` did not terminate
+    Pagination {
+        collection: String,
+        detail: String,
+    },
+    /// The `Config` cannot be used to build a client
+    InvalidConfig(String),
+    /// A local file could not be read, e.g. a firmware image to upload
+    Io(std::io::Error),
+    /// The BMC presented a certificate whose SHA-256 fingerprint does not match
+    /// `Config::pinned_cert_sha256`
+    CertificateMismatch {
+        expected: String,
+        found: String,
+    },
+    /// The host does not implement the requested feature
+    NotSupported(String),
+    /// A value was rejected before being sent because the host does not allow it
+    InvalidValue {
+        property: String,
+        value: String,
+        allowed: Vec<String>,
+    },
+    /// A collection expected to hold exactly one member held some other number
+    UnexpectedMemberCount {
+        collection: String,
+        count: u64,
+    },
+}
+
+impl RedfishError {
+    /// Categorize a failure to get any response from the server by inspecting
+    /// the error's source chain
+    pub(crate) fn transport(e: reqwest::Error) -> Self {
+        match classify(&e, e.is_connect(), e.is_timeout()) {
+            TransportFailure::ConnectTimeout => RedfishError::ConnectTimeout(e),
+            TransportFailure::ReadTimeout => RedfishError::ReadTimeout(e),
+            TransportFailure::Tls => RedfishError::TlsError(e),
+            TransportFailure::Dns => RedfishError::Dns(e),
+            TransportFailure::Refused => RedfishError::ConnectionRefused(e),
+            TransportFailure::Other => RedfishError::Http(e),
+        }
+    }
+
+    /// Whether the request reached the server but no answer came back, either
+    /// because none arrived in time or the connection was dropped mid-response.
+    /// Managers commonly drop the connection before answering an action that
+    /// restarts them, so such actions treat this as success.
+    pub(crate) fn no_response(&self) -> bool {
+        match self {
+            RedfishError::ReadTimeout(_) | RedfishError::Timeout { .. } => true,
+            RedfishError::Http(e) => connection_dropped(e),
+            _ => false,
+        }
+    }
+
+    /// The HTTP status the server answered with, if the failure was a non-success status
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            RedfishError::Http(e) => e.status(),
+            RedfishError::UnexpectedStatus { status, .. } => {
+                reqwest::StatusCode::from_u16(*status).ok()
+            }
+            RedfishError::PreconditionFailed { .. } => {
+                Some(reqwest::StatusCode::PRECONDITION_FAILED)
+            }
+            RedfishError::Unauthorized { .. } => Some(reqwest::StatusCode::UNAUTHORIZED),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RedfishError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RedfishError::Http(e) => write!(f, "HTTP error: {}", e),
+            RedfishError::Timeout { after, .. } => {
+                write!(f, "timed out after {:?}", after)
+            }
+            RedfishError::ConnectTimeout(e) => write!(f, "timed out connecting: {}", e),
+            RedfishError::ReadTimeout(e) => write!(f, "timed out waiting for a response: {}", e),
+            RedfishError::TlsError(e) => write!(f, "TLS handshake failed: {}", e),
+            RedfishError::Dns(e) => write!(f, "could not resolve host: {}", e),
+            RedfishError::ConnectionRefused(e) => write!(f, "connection refused: {}", e),
+            RedfishError::Deserialize {
+                endpoint,
+                body,
+                source,
+            } => {
+                write!(
+                    f,
+                    "could not parse the response from {}: {} in {:?}",
+                    endpoint, source, body
+                )
+            }
+            RedfishError::UnexpectedStatus { status, body } if body.is_empty() => {
+                write!(f, "server answered with status {}", status)
+            }
+            RedfishError::UnexpectedStatus { status, body } => {
+                write!(f, "server answered with status {}: {}", status, body)
+            }
+            RedfishError::PreconditionFailed { .. } => {
+                write!(f, "the resource was modified since its ETag was read")
+            }
+            RedfishError::Unauthorized { .. } => {
+                write!(f, "not authorized, even after logging in again")
+            }
+            RedfishError::Pagination { collection, detail } => {
+                write!(f, "could not page through {}: {}", collection, detail)
+            }
+            RedfishError::InvalidConfig(why) => write!(f, "invalid configuration: {}", why),
+            RedfishError::Io(e) => write!(f, "I/O error: {}", e),
+            RedfishError::CertificateMismatch { expected, found } => write!(
+                f,
+                "certificate fingerprint mismatch: expected sha256 {}, found {}",
+                expected, found
+            ),
+            RedfishError::NotSupported(what) => write!(f, "not supported by this host: {}", what),
+            RedfishError::InvalidValue {
+                property,
+                value,
+                allowed,
+            } => write!(
+                f,
+                "{} is not an allowed value for {} (allowed: {})",
+                value,
+                property,
+                allowed.join(", ")
+            ),
+            RedfishError::UnexpectedMemberCount { collection, count } => write!(
+                f,
+                "expected exactly one member in {}, found {}",
+                collection, count
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RedfishError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RedfishError::Http(e)
+            | RedfishError::ConnectTimeout(e)
+            | RedfishError::ReadTimeout(e)
+            | RedfishError::TlsError(e)
+            | RedfishError::Dns(e)
+            | RedfishError::ConnectionRefused(e) => Some(e),
+            RedfishError::Deserialize { source, .. } => Some(source),
+            RedfishError::Timeout {
+                source: Some(e), ..
+            } => Some(e),
+            RedfishError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for RedfishError {
+    fn from(e: reqwest::Error) -> Self {
+        RedfishError::Http(e)
+    }
+}
+
+impl From<std::io::Error> for RedfishError {
+    fn from(e: std::io::Error) -> Self {
+        RedfishError::Io(e)
+    }
+}
+
+/// How much of a response body `RedfishError::Deserialize` keeps
+const SNIPPET_LEN: usize = 500;
+
+/// The first `SNIPPET_LEN` bytes of `body`, cut back to a character boundary
+pub(crate) fn snippet(body: &[u8]) -> String {
+    let mut body = String::from_utf8_lossy(&body[..body.len().min(SNIPPET_LEN)]).into_owned();
+    // A multi-byte character cut in half at the end decodes to U+FFFD
+    if body.len() >= SNIPPET_LEN {
+        while body.ends_with('\u{fffd}') {
+            body.pop();
+        }
+    }
+    body
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TransportFailure {
+    ConnectTimeout,
+    ReadTimeout,
+    Tls,
+    Dns,
+    Refused,
+    Other,
+}
+
+/// reqwest only flags connect and timeout failures, so anything more specific
+/// is found by walking the source chain down to the io or rustls error
+fn classify(
+    err: &(dyn std::error::Error + 'static),
+    connect: bool,
+    timeout: bool,
+) -> TransportFailure {
+    let mut source = Some(err);
+    while let Some(e) = source {
+        if e.downcast_ref::<rustls::Error>().is_some() {
+            return TransportFailure::Tls;
+        }
+        if let Some(io) = e.downcast_ref::<std::io::Error>() {
+            // io::Error::source skips the wrapped error, so check it directly
+            if io
+                .get_ref()
+                .is_some_and(|inner| inner.is::<rustls::Error>())
+            {
+                return TransportFailure::Tls;
+            }
+            match io.kind() {
+                std::io::ErrorKind::ConnectionRefused => return TransportFailure::Refused,
+                std::io::ErrorKind::TimedOut if connect => {
+                    return TransportFailure::ConnectTimeout;
+                }
+                std::io::ErrorKind::TimedOut => return TransportFailure::ReadTimeout,
+                _ => {}
+            }
+        }
+        let message = e.to_string();
+        if message.contains("dns error") || message.contains("failed to lookup address") {
+            return TransportFailure::Dns;
+        }
+        source = e.source();
+    }
+    match (timeout, connect) {
+        (true, true) => TransportFailure::ConnectTimeout,
+        (true, false) => TransportFailure::ReadTimeout,
+        _ => TransportFailure::Other,
+    }
+}
+
+/// Whether the connection was closed or reset after the request was written,
+/// before a complete response was read
+fn connection_dropped(err: &(dyn std::error::Error + 'static)) -> bool {
+    let mut source = Some(err);
+    while let Some(e) = source {
+        if let Some(io) = e.downcast_ref::<std::io::Error>() {
+            if matches!(
+                io.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::UnexpectedEof
+            ) {
+                return true;
+            }
+        }
+        // hyper's incomplete message error is not exposed through reqwest
+        if e.to_string()
+            .contains("connection closed before message completed")
+        {
+            return true;
+        }
+        source = e.source();
+    }
+    false
+}
+
+#[test]
+fn test_classify_transport_failure() {
+    use std::io::{Error, ErrorKind};
+
+    #[derive(Debug)]
+    struct Wrapper(Box<dyn std::error::Error + Send + Sync>);
+    impl fmt::Display for Wrapper {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "error trying to connect")
+        }
+    }
+    impl std::error::Error for Wrapper {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&*self.0)
+        }
+    }
+
+    let refused = Wrapper(Box::new(Error::from(ErrorKind::ConnectionRefused)));
+    assert_eq!(classify(&refused, true, false), TransportFailure::Refused);
+
+    let timed_out = Wrapper(Box::new(Error::from(ErrorKind::TimedOut)));
+    assert_eq!(
+        classify(&timed_out, true, true),
+        TransportFailure::ConnectTimeout
+    );
+    assert_eq!(
+        classify(&timed_out, false, true),
+        TransportFailure::ReadTimeout
+    );
+
+    let dns = Wrapper(Box::new(Error::other(
+        "failed to lookup address information: Name or service not known",
+    )));
+    assert_eq!(classify(&dns, true, false), TransportFailure::Dns);
+
+    let tls = Wrapper(Box::new(Error::new(
+        ErrorKind::InvalidData,
+        rustls::Error::General("invalid peer certificate".to_string()),
+    )));
+    assert_eq!(classify(&tls, true, false), TransportFailure::Tls);
+
+    let other = Wrapper(Box::new(Error::from(ErrorKind::BrokenPipe)));
+    assert_eq!(classify(&other, false, false), TransportFailure::Other);
+    assert_eq!(classify(&other, false, true), TransportFailure::ReadTimeout);
+
+    let reset = Wrapper(Box::new(Error::from(ErrorKind::ConnectionReset)));
+    assert!(connection_dropped(&reset));
+    let incomplete = Wrapper(Box::new(Error::other(
+        "connection closed before message completed",
+    )));
+    assert!(connection_dropped(&incomplete));
+    assert!(!connection_dropped(&refused));
+    assert!(!connection_dropped(&dns));
+}
+
+#[test]
+fn test_unexpected_status() {
+    let e = RedfishError::UnexpectedStatus {
+        status: 404,
+        body: String::new(),
+    };
+    assert_eq!(e.status(), Some(reqwest::StatusCode::NOT_FOUND));
+    assert_eq!(e.to_string(), "server answered with status 404");
+
+    let source = serde_json::from_str::<u8>("[]").unwrap_err();
+    let e = RedfishError::Deserialize {
+        endpoint: "Systems/1/".to_string(),
+        body: snippet(b"[]"),
+        source,
+    };
+    assert!(e.to_string().ends_with("in \"[]\""), "{}", e);
+
+    let e = RedfishError::PreconditionFailed {
+        body: String::new(),
+    };
+    assert_eq!(e.status(), Some(reqwest::StatusCode::PRECONDITION_FAILED));
+}
+
+#[test]
+fn test_snippet() {
+    assert_eq!(snippet(b"{\"Id\": 1}"), "{\"Id\": 1}");
+    let long = "é".repeat(SNIPPET_LEN);
+    let cut = snippet(long.as_bytes());
+    assert_eq!(cut, "é".repeat(SNIPPET_LEN / 2));
+    let long = format!("x{}", long);
+    assert_eq!(snippet(long.as_bytes()).len(), SNIPPET_LEN - 1);
+}
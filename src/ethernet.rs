@@ -0,0 +1,46 @@
+use crate::common::*;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct IPv4Address {
+    pub address: Option<String>,
+    /// e.g. `Static` or `DHCP`
+    pub address_origin: Option<String>,
+    pub gateway: Option<String>,
+    pub subnet_mask: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct EthernetInterface {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub full_duplex: Option<bool>,
+    pub id: String,
+    pub interface_enabled: Option<bool>,
+    #[serde(rename = "IPv4Addresses", default)]
+    pub ipv4_addresses: Vec<IPv4Address>,
+    /// e.g. `LinkUp`, `LinkDown` or `NoLink`
+    pub link_status: Option<String>,
+    #[serde(rename = "MACAddress")]
+    pub mac_address: Option<String>,
+    pub name: String,
+    pub speed_mbps: Option<i64>,
+    pub status: Option<SomeStatus>,
+}
+
+#[test]
+fn test_ethernet_interface_parser() {
+    let test_data = include_str!("../tests/ethernet-interface.json");
+    let result: EthernetInterface = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
+
+pub type EthernetInterfaces = Collection;
+
+#[test]
+fn test_ethernet_interfaces_parser() {
+    let test_data = include_str!("../tests/ethernet-interfaces.json");
+    let result: EthernetInterfaces = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
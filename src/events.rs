@@ -0,0 +1,289 @@
+use crate::common::*;
+use crate::{Redfish, RedfishError};
+use reqwest::blocking::Response;
+use std::io::{BufRead, BufReader};
+
+/// Where the `EventService` serves its Server-Sent Events stream
+pub const SSE_PATH: &str = "EventService/SSE";
+
+/// The kinds of event a subscription can ask for. Newer services deprecate
+/// `EventTypes` in favour of `RegistryPrefixes`, but still accept it.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum EventType {
+    Alert,
+    StatusChange,
+    ResourceAdded,
+    ResourceRemoved,
+    ResourceUpdated,
+    MetricReport,
+    #[serde(other)]
+    Other,
+}
+
+/// A subscription registered with the `EventService`, which the BMC pushes
+/// matching events to
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct EventDestination {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub context: Option<String>,
+    pub destination: String,
+    #[serde(default)]
+    pub event_types: Vec<EventType>,
+    pub id: String,
+    pub name: String,
+    pub protocol: Option<String>,
+}
+
+pub type EventSubscriptionCollection = Collection;
+
+/// One occurrence within a `RedfishEvent`
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct EventRecord {
+    pub event_id: Option<String>,
+    pub event_timestamp: Option<String>,
+    pub event_type: Option<EventType>,
+    pub message: Option<String>,
+    #[serde(default)]
+    pub message_args: Vec<String>,
+    pub message_id: Option<String>,
+    pub origin_of_condition: Option<ODataId>,
+    pub severity: Option<String>,
+}
+
+/// An event payload, as pushed to subscribers or sent on the SSE stream. It
+/// is not a resource, so it carries no `@odata.id`.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RedfishEvent {
+    #[serde(rename = "@odata.type")]
+    pub odata_type: Option<String>,
+    pub context: Option<String>,
+    #[serde(default)]
+    pub events: Vec<EventRecord>,
+    pub id: Option<String>,
+    pub name: Option<String>,
+}
+
+/// Assembles Server-Sent Events from the stream a line at a time
+#[derive(Debug, Default)]
+pub(crate) struct SseParser {
+    data: String,
+    /// The `id` of the last event, sent as `Last-Event-ID` on reconnecting
+    pub(crate) last_id: Option<String>,
+}
+
+impl SseParser {
+    /// Take one line, returning the data of the event it completes, if any
+    pub(crate) fn feed(&mut self, line: &str) -> Option<String> {
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            if self.data.is_empty() {
+                return None;
+            }
+            return Some(std::mem::take(&mut self.data));
+        }
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "data" => {
+                if !self.data.is_empty() {
+                    self.data.push('\n');
+                }
+                self.data.push_str(value);
+            }
+            "id" => self.last_id = Some(value.to_string()),
+            // Comments (an empty field), `event` and `retry` are not needed
+            _ => {}
+        }
+        None
+    }
+
+    /// Drop a partly received event, as when the connection is lost
+    pub(crate) fn reset(&mut self) {
+        self.data.clear();
+    }
+}
+
+/// The events sent on the `EventService` SSE stream, returned by
+/// `Redfish::stream_events`. `next` blocks until the BMC sends an event. A
+/// dropped connection is reopened, resuming after the last event where the
+/// service supports it; if it fails to reopen, or closes again before
+/// sending anything, the error is yielded and the stream ends.
+/// `Config::timeout` is not applied to the stream; if a read times out anyway,
+/// e.g. under the timeout of a `Client` passed to `Redfish::new`, the quiet
+/// stream is reopened rather than ended.
+pub struct EventStream<'a> {
+    redfish: &'a Redfish,
+    reader: Option<BufReader<Response>>,
+    parser: SseParser,
+    received: bool,
+    done: bool,
+}
+
+impl<'a> EventStream<'a> {
+    pub(crate) fn new(redfish: &'a Redfish) -> Self {
+        EventStream {
+            redfish,
+            reader: None,
+            parser: SseParser::default(),
+            received: false,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for EventStream<'_> {
+    type Item = Result<RedfishEvent, RedfishError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut line = String::new();
+        loop {
+            let reader = match &mut self.reader {
+                Some(reader) => reader,
+                None => match self
+                    .redfish
+                    .open_event_stream(self.parser.last_id.as_deref())
+                {
+                    Ok(res) => {
+                        self.received = false;
+                        self.reader.insert(BufReader::new(res))
+                    }
+                    Err(e) => {
+                        self.done = true;
+                        return Some(Err(e));
+                    }
+                },
+            };
+            line.clear();
+            let closed = match reader.read_line(&mut line) {
+                Ok(0) => {
+                    std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "event stream closed")
+                }
+                Ok(_) => {
+                    self.received = true;
+                    if let Some(data) = self.parser.feed(&line) {
+                        return Some(serde_json::from_str(&data).map_err(|source| {
+                            RedfishError::Deserialize {
+                                endpoint: SSE_PATH.to_string(),
+                                body: crate::error::snippet(data.as_bytes()),
+                                source,
+                            }
+                        }));
+                    }
+                    continue;
+                }
+                Err(e) if read_timed_out(&e) => {
+                    self.reader = None;
+                    self.parser.reset();
+                    continue;
+                }
+                Err(e) => e,
+            };
+            self.reader = None;
+            self.parser.reset();
+            if !self.received {
+                self.done = true;
+                return Some(Err(RedfishError::Io(closed)));
+            }
+        }
+    }
+}
+
+/// Whether a read from the stream failed only because nothing arrived in time
+fn read_timed_out(e: &std::io::Error) -> bool {
+    e.kind() == std::io::ErrorKind::TimedOut
+        || e.get_ref()
+            .and_then(|inner| inner.downcast_ref::<reqwest::Error>())
+            .is_some_and(reqwest::Error::is_timeout)
+}
+
+#[test]
+fn test_read_timed_out() {
+    use std::io::{Error, ErrorKind};
+    assert!(read_timed_out(&Error::from(ErrorKind::TimedOut)));
+    assert!(!read_timed_out(&Error::from(ErrorKind::UnexpectedEof)));
+    assert!(!read_timed_out(&Error::other("connection reset")));
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NewSubscription<'a> {
+    pub destination: &'a str,
+    pub event_types: &'a [EventType],
+    pub protocol: &'a str,
+}
+
+#[test]
+fn test_event_destination_parser() {
+    let test_data = include_str!("../tests/event-destination.json");
+    let result: EventDestination = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(
+        result.destination,
+        "https://monitor.example.com:8443/redfish"
+    );
+    assert_eq!(
+        result.event_types,
+        vec![EventType::Alert, EventType::StatusChange, EventType::Other]
+    );
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_event_subscription_collection_parser() {
+    let test_data = include_str!("../tests/event-subscriptions.json");
+    let result: EventSubscriptionCollection = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.members.len(), 1);
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_new_subscription() {
+    let subscription = NewSubscription {
+        destination: "https://monitor.example.com:8443/redfish",
+        event_types: &[EventType::Alert, EventType::ResourceUpdated],
+        protocol: "Redfish",
+    };
+    assert_eq!(
+        serde_json::to_value(&subscription).unwrap(),
+        serde_json::json!({
+            "Destination": "https://monitor.example.com:8443/redfish",
+            "EventTypes": ["Alert", "ResourceUpdated"],
+            "Protocol": "Redfish"
+        })
+    );
+}
+
+#[test]
+fn test_redfish_event_parser() {
+    let test_data = include_str!("../tests/event.json");
+    let result: RedfishEvent = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.events.len(), 1);
+    let event = &result.events[0];
+    assert_eq!(event.event_type, Some(EventType::Alert));
+    assert_eq!(
+        event.origin_of_condition.as_ref().unwrap().path(),
+        "Systems/1/"
+    );
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_sse_parser() {
+    let mut parser = SseParser::default();
+    let stream =
+        ": keep-alive\r\n\r\nid: 41\r\ndata: {\"Id\":\r\ndata: \"41\"}\r\n\r\nid: 42\ndata:{}\n\n";
+    let events: Vec<String> = stream
+        .split_inclusive('\n')
+        .filter_map(|line| parser.feed(line))
+        .collect();
+    assert_eq!(events, vec!["{\"Id\":\n\"41\"}", "{}"]);
+    assert_eq!(parser.last_id.as_deref(), Some("42"));
+    let event: RedfishEvent = serde_json::from_str(&events[0]).unwrap();
+    assert_eq!(event.id.as_deref(), Some("41"));
+}
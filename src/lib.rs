@@ -1,166 +1,3128 @@
 #[macro_use]
 extern crate serde_derive;
 
+pub mod accounts;
+pub mod bios;
+pub mod boot;
+pub mod chassis;
+pub mod client;
 pub mod common;
+pub mod composition;
+pub mod error;
+pub mod ethernet;
+pub mod events;
+pub mod log;
 pub mod manager;
+pub mod memory;
+pub mod network_adapter;
+pub mod pagination;
 pub mod power;
+pub mod processor;
+pub mod registry;
+pub mod retry;
+pub mod secret;
+pub mod secure_boot;
+pub mod service_root;
+pub mod session;
+pub mod settings;
 pub mod storage;
+pub mod storage_standard;
+pub mod system;
+pub mod task;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod thermal;
+mod tls;
+pub mod transaction;
+pub mod update;
+pub mod virtual_media;
 
-use reqwest::{header::HeaderValue, header::ACCEPT, header::CONTENT_TYPE, blocking::Client};
+pub use crate::client::RedfishClient;
+pub use crate::error::RedfishError;
+pub use crate::retry::RetryPolicy;
+pub use crate::secret::Secret;
+
+use reqwest::{
+    Method, StatusCode, Url, blocking::Client, blocking::Request, blocking::RequestBuilder,
+    blocking::Response, blocking::multipart, header::ACCEPT, header::CONTENT_TYPE, header::ETAG,
+    header::HeaderMap, header::HeaderValue, header::IF_MATCH, header::LOCATION,
+};
+use serde::Serialize;
 use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+pub struct Config {
+    pub user: Option<String>,
+    pub endpoint: String,
+    /// Redacted from debug output, and zeroized on drop with the `zeroize` feature
+    pub password: Option<Secret>,
+    pub port: Option<u16>,
+    /// Accept header sent instead of `application/json`, e.g.
+    /// `application/json;odata.metadata=minimal` to leave out OData
+    /// annotations, or `application/json;charset=utf-8` for BMCs that key
+    /// behaviour off it
+    pub accept_override: Option<String>,
+    /// SHA-256 fingerprint of the BMC's certificate, as hex with or without
+    /// colons. When set, `Redfish::with_config` trusts that certificate and
+    /// nothing else.
+    pub pinned_cert_sha256: Option<String>,
+    /// Local address to send requests from, for management hosts where BMC
+    /// traffic has to leave through a specific interface. Applied by
+    /// `Redfish::with_config`.
+    pub local_address: Option<IpAddr>,
+    /// Proxy every request through this URL, e.g. `http://jump.example.com:3128`
+    /// or `socks5://127.0.0.1:1080`. Applied by `Redfish::with_config`.
+    pub proxy: Option<String>,
+    /// The Redfish protocol version, which puts paths under `redfish/<version>/`.
+    /// When `None`, `endpoint` is expected to already include the service root
+    /// path, e.g. `bmc.example.com/redfish/v1`.
+    pub api_version: Option<ApiVersion>,
+    /// Accept any certificate the BMC presents, as most ship self-signed.
+    /// Applied by `Redfish::with_config`, ignored when a pin is set.
+    pub accept_invalid_certs: bool,
+    /// A PEM encoded CA certificate to trust in addition to the system roots,
+    /// for BMCs with certificates issued by a private CA. Applied by
+    /// `Redfish::with_config`, ignored when a pin is set.
+    pub ca_cert_pem: Option<Vec<u8>>,
+    /// A PEM encoded client certificate, presented to BMCs that require
+    /// mutual TLS. Needs `client_key_pem` as well, and can't be combined with
+    /// a pin. Applied by `Redfish::with_config`.
+    pub client_cert_pem: Option<Vec<u8>>,
+    /// The PEM encoded private key of `client_cert_pem`
+    pub client_key_pem: Option<Vec<u8>>,
+    /// How long each request may take in total before failing with
+    /// `RedfishError::Timeout`. Failing to connect in time is still reported
+    /// as `RedfishError::ConnectTimeout`
+    pub timeout: Option<Duration>,
+    /// Retrying of transient failures, off unless opted into
+    pub retry: RetryPolicy,
+    /// How requests are authenticated. A session created with
+    /// `Redfish::login` is used instead whenever one is active.
+    pub auth: AuthMethod,
+    /// Headers sent with every request, e.g. `OData-Version: 4.0` for BMCs
+    /// that insist on it. These replace the crate's own `Accept` and
+    /// `Content-Type`, but not the headers particular requests need, such as
+    /// the `Accept` of `Redfish::get_metadata` or credentials.
+    pub default_headers: HeaderMap,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            user: None,
+            endpoint: String::new(),
+            password: None,
+            port: None,
+            accept_override: None,
+            pinned_cert_sha256: None,
+            local_address: None,
+            proxy: None,
+            api_version: None,
+            accept_invalid_certs: false,
+            ca_cert_pem: None,
+            client_cert_pem: None,
+            client_key_pem: None,
+            timeout: None,
+            retry: RetryPolicy::none(),
+            auth: AuthMethod::Basic,
+            default_headers: HeaderMap::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Start building a `Config`, as an alternative to filling in the fields
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+    }
+
+    /// Set the password, from anything convertible to a `Secret`
+    pub fn with_password(mut self, password: impl Into<Secret>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// A config for `host` with credentials from `REDFISH_USER` and
+    /// `REDFISH_PASSWORD`, and the port from `REDFISH_PORT` if it is set
+    pub fn from_env(host: &str) -> Result<Config, RedfishError> {
+        Self::from_lookup(host, |name| std::env::var(name).ok())
+    }
+
+    fn from_lookup(
+        host: &str,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Config, RedfishError> {
+        let required = |name: &str| {
+            lookup(name).ok_or_else(|| {
+                RedfishError::InvalidConfig(format!("environment variable {} is not set", name))
+            })
+        };
+        let mut builder = Config::builder()
+            .host(host)
+            .user(required("REDFISH_USER")?)
+            .password(required("REDFISH_PASSWORD")?);
+        if let Some(port) = lookup("REDFISH_PORT") {
+            let port = port.parse().map_err(|_| {
+                RedfishError::InvalidConfig(format!("REDFISH_PORT {} is not a port number", port))
+            })?;
+            builder = builder.port(port);
+        }
+        builder.build()
+    }
+
+    /// Read a config from a TOML file with the BMC's `host` and optionally
+    /// `user`, `password`, `port` and `accept_invalid_certs`, e.g.
+    ///
+    /// ```toml
+    /// host = "10.0.0.5"
+    /// user = "admin"
+    /// password = "hunter2"
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml(path: impl AsRef<std::path::Path>) -> Result<Config, RedfishError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        Self::from_toml_str(&text).map_err(|e| match e {
+            RedfishError::InvalidConfig(why) => {
+                RedfishError::InvalidConfig(format!("{}: {}", path.display(), why))
+            }
+            e => e,
+        })
+    }
+
+    #[cfg(feature = "toml")]
+    fn from_toml_str(text: &str) -> Result<Config, RedfishError> {
+        let file: ConfigFile = toml::from_str(text)
+            .map_err(|e| RedfishError::InvalidConfig(e.message().to_string()))?;
+        let mut builder = Config::builder()
+            .host(file.host)
+            .accept_invalid_certs(file.accept_invalid_certs);
+        if let Some(user) = file.user {
+            builder = builder.user(user);
+        }
+        if let Some(password) = file.password {
+            builder = builder.password(password);
+        }
+        if let Some(port) = file.port {
+            builder = builder.port(port);
+        }
+        builder.build()
+    }
+}
+
+/// The settings `Config::from_toml` reads
+#[cfg(feature = "toml")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    host: String,
+    user: Option<String>,
+    password: Option<String>,
+    port: Option<u16>,
+    #[serde(default)]
+    accept_invalid_certs: bool,
+}
+
+/// The Redfish protocol version in the service root path. Every revision of
+/// the specification so far serves its resources under `/redfish/v1`, so `V1`
+/// is the only version there is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApiVersion {
+    #[default]
+    V1,
+}
+
+impl std::str::FromStr for ApiVersion {
+    type Err = RedfishError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "v1" | "V1" => Ok(ApiVersion::V1),
+            other => Err(RedfishError::InvalidConfig(format!(
+                "{} is not a Redfish API version, only v1 exists",
+                other
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ApiVersion::V1 => write!(f, "v1"),
+        }
+    }
+}
+
+/// The credentials sent with each request that is not part of a session
+#[derive(Debug, Default)]
+pub enum AuthMethod {
+    /// Send nothing, for services that allow anonymous reads
+    None,
+    /// HTTP Basic auth with `Config::user` and `Config::password`, or nothing
+    /// when no user is set
+    #[default]
+    Basic,
+    /// Only authenticate through a session, so `user` and `password` are sent
+    /// once to `Redfish::login` and requests made before it carry nothing
+    Session,
+    /// An `Authorization: Bearer` token, e.g. for an API gateway using OAuth
+    Bearer(Secret),
+}
+
+/// Chainable construction of a `Config`, checked when it is built
+#[derive(Debug, Default)]
+pub struct ConfigBuilder {
+    host: String,
+    user: Option<String>,
+    password: Option<Secret>,
+    port: Option<u16>,
+    accept: Option<String>,
+    proxy: Option<String>,
+    api_version: ApiVersion,
+    accept_invalid_certs: bool,
+    ca_cert_pem: Option<Vec<u8>>,
+    client_cert_pem: Option<Vec<u8>>,
+    client_key_pem: Option<Vec<u8>>,
+    timeout: Option<Duration>,
+    retry: Option<RetryPolicy>,
+    auth: AuthMethod,
+    default_headers: HeaderMap,
+}
+
+impl ConfigBuilder {
+    /// The BMC's host name or address, without scheme or path
+    pub fn host(mut self, host: impl Into<String>) -> Self {
+        self.host = host.into();
+        self
+    }
+
+    pub fn user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    pub fn password(mut self, password: impl Into<Secret>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Send this Accept header instead of `application/json`
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Reach the BMC through an HTTP or SOCKS5 proxy
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Defaults to `ApiVersion::V1`
+    pub fn api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = api_version;
+        self
+    }
+
+    /// Accept self-signed and otherwise untrusted certificates
+    pub fn accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    /// Trust a PEM encoded CA certificate in addition to the system roots
+    pub fn ca_cert_pem(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.ca_cert_pem = Some(pem.into());
+        self
+    }
+
+    /// Authenticate with a PEM encoded client certificate and its private key
+    pub fn client_cert_pem(mut self, cert: impl Into<Vec<u8>>, key: impl Into<Vec<u8>>) -> Self {
+        self.client_cert_pem = Some(cert.into());
+        self.client_key_pem = Some(key.into());
+        self
+    }
+
+    /// Fail any request that takes longer than `timeout`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Retry transient failures, e.g. with `RetryPolicy::default()`
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Defaults to `AuthMethod::Basic`
+    pub fn auth(mut self, auth: AuthMethod) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Send `headers` with every request
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Check the settings fit together: a host is required, and a password
+    /// is only meaningful along with a user
+    pub fn build(self) -> Result<Config, RedfishError> {
+        if self.host.trim().is_empty() {
+            return Err(RedfishError::InvalidConfig("host is empty".to_string()));
+        }
+        if self.password.is_some() && self.user.is_none() {
+            return Err(RedfishError::InvalidConfig(
+                "password given without a user".to_string(),
+            ));
+        }
+        Ok(Config {
+            user: self.user,
+            endpoint: self.host,
+            password: self.password,
+            port: self.port,
+            accept_override: self.accept,
+            proxy: self.proxy,
+            api_version: Some(self.api_version),
+            accept_invalid_certs: self.accept_invalid_certs,
+            ca_cert_pem: self.ca_cert_pem,
+            client_cert_pem: self.client_cert_pem,
+            client_key_pem: self.client_key_pem,
+            timeout: self.timeout,
+            retry: self.retry.unwrap_or_else(RetryPolicy::none),
+            auth: self.auth,
+            default_headers: self.default_headers,
+            ..Config::default()
+        })
+    }
+}
+
+/// A response as the server sent it, returned by `Redfish::get_raw`
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    pub status: u16,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl RawResponse {
+    /// The `ETag` header, for use with `If-Match`
+    pub fn etag(&self) -> Option<&str> {
+        self.headers.get(ETAG).and_then(|e| e.to_str().ok())
+    }
+}
+
+pub struct Redfish {
+    pub client: Client,
+    pub config: Config,
+    pin: Option<Arc<tls::CertificatePin>>,
+    session: Mutex<Option<session::SessionToken>>,
+    /// `https` except for `Redfish::mock`, whose test servers speak plain HTTP
+    scheme: &'static str,
+}
+
+impl Redfish {
+    pub fn new(client: Client, mut config: Config) -> Self {
+        config.endpoint = normalize_host(&config.endpoint).to_string();
+        Redfish {
+            client,
+            config,
+            pin: None,
+            session: Mutex::new(None),
+            scheme: "https",
+        }
+    }
+
+    /// Use one `Client` for many hosts, e.g. when polling a fleet. Every
+    /// `Redfish` built from the same `client` draws on a single connection pool
+    /// and TLS session cache, rather than each holding idle connections and
+    /// redoing full handshakes. As with `new`, the TLS and network settings
+    /// in `config` are ignored in favour of the client's own.
+    pub fn from_shared_client(client: &Client, config: Config) -> Self {
+        // A `Client` is a handle to shared state, so the clone reuses its pool
+        Redfish::new(client.clone(), config)
+    }
+
+    /// Build a client honoring the TLS and network settings in `config`. Use `new` instead
+    /// to supply a custom `Client`, in which case those settings are ignored.
+    pub fn with_config(mut config: Config) -> Result<Self, RedfishError> {
+        config.endpoint = normalize_host(&config.endpoint).to_string();
+        if config.endpoint.is_empty() {
+            return Err(RedfishError::InvalidConfig("endpoint is empty".to_string()));
+        }
+        let mut builder = Client::builder();
+        let mut pin = None;
+        match (&config.client_cert_pem, &config.client_key_pem) {
+            (Some(cert), Some(key)) => {
+                if config.pinned_cert_sha256.is_some() {
+                    return Err(RedfishError::InvalidConfig(
+                        "client_cert_pem can't be combined with pinned_cert_sha256".to_string(),
+                    ));
+                }
+                // rustls reads the key and certificate from a single buffer
+                let mut pem = key.clone();
+                pem.push(b'\n');
+                pem.extend_from_slice(cert);
+                let identity = reqwest::Identity::from_pem(&pem).map_err(|e| {
+                    RedfishError::InvalidConfig(format!(
+                        "client_cert_pem and client_key_pem are not a PEM certificate and key: {}",
+                        e
+                    ))
+                })?;
+                builder = builder.identity(identity);
+            }
+            (None, None) => {}
+            _ => {
+                return Err(RedfishError::InvalidConfig(
+                    "client_cert_pem and client_key_pem must be given together".to_string(),
+                ));
+            }
+        }
+        if let Some(fingerprint) = &config.pinned_cert_sha256 {
+            let p = Arc::new(tls::CertificatePin::new(fingerprint));
+            if p.expected.len() != 64 {
+                return Err(RedfishError::InvalidConfig(format!(
+                    "pinned_cert_sha256 {} is not a SHA-256 fingerprint",
+                    fingerprint
+                )));
+            }
+            builder = builder.use_preconfigured_tls(tls::pinned_client_config(p.clone()));
+            pin = Some(p);
+        } else {
+            if config.accept_invalid_certs {
+                builder = builder.danger_accept_invalid_certs(true);
+            }
+            if let Some(pem) = &config.ca_cert_pem {
+                let ca = reqwest::Certificate::from_pem(pem).map_err(|e| {
+                    RedfishError::InvalidConfig(format!(
+                        "ca_cert_pem is not a PEM certificate: {}",
+                        e
+                    ))
+                })?;
+                builder = builder.add_root_certificate(ca);
+            }
+        }
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+        if let Some(url) = &config.proxy {
+            let proxy = reqwest::Proxy::all(url.as_str()).map_err(|e| {
+                RedfishError::InvalidConfig(format!(
+                    "proxy {} is not a valid proxy URL: {}",
+                    url, e
+                ))
+            })?;
+            builder = builder.proxy(proxy);
+        }
+        let client = builder.build()?;
+        let redfish = Redfish {
+            client,
+            config,
+            pin,
+            session: Mutex::new(None),
+            scheme: "https",
+        };
+        redfish.build_uri("")?;
+        Ok(redfish)
+    }
+
+    /// The full URI of `api`, which may carry a query such as `?$skip=50`.
+    /// Characters not allowed in a path, e.g. spaces in an id, are
+    /// percent-encoded.
+    fn build_uri(&self, api: &str) -> Result<Url, RedfishError> {
+        let base = match self.config.port {
+            Some(p) => format!("{}://{}:{}/", self.scheme, self.config.endpoint, p),
+            None => format!("{}://{}/", self.scheme, self.config.endpoint),
+        };
+        let mut url = Url::parse(&base).map_err(|e| {
+            RedfishError::InvalidConfig(format!("{} is not a valid URI: {}", base, e))
+        })?;
+        let root = match self.config.api_version {
+            Some(v) => format!("redfish/{}/", v),
+            None => String::new(),
+        };
+        let (path, query) = match api.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (api, None),
+        };
+        let prefix = url.path().to_string();
+        url.set_path(&format!("{}{}{}", prefix, root, path));
+        url.set_query(query);
+        Ok(url)
+    }
+
+    fn accept(&self) -> &str {
+        self.config
+            .accept_override
+            .as_deref()
+            .unwrap_or("application/json")
+    }
+
+    /// Start a request against the endpoint with the standard headers, credentials
+    /// and `Config::timeout` applied
+    fn request(&self, method: Method, api: &str) -> Result<RequestBuilder, RedfishError> {
+        let builder = self.untimed_request(method, api)?;
+        Ok(match self.config.timeout {
+            Some(timeout) => builder.timeout(timeout),
+            None => builder,
+        })
+    }
+
+    /// `request` without `Config::timeout`, for responses that stay open such as
+    /// the SSE stream, which a total timeout would cut off however busy it is
+    fn untimed_request(&self, method: Method, api: &str) -> Result<RequestBuilder, RedfishError> {
+        let accept = HeaderValue::from_str(self.accept()).map_err(|_| {
+            RedfishError::InvalidConfig(format!(
+                "accept_override {} is not a valid header value",
+                self.accept()
+            ))
+        })?;
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, accept);
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        for (name, value) in &self.config.default_headers {
+            headers.insert(name, value.clone());
+        }
+        let builder = self
+            .client
+            .request(method, self.build_uri(api)?)
+            .headers(headers);
+        if let Some(session) = self.session.lock().unwrap().as_ref() {
+            return Ok(builder.header(session::X_AUTH_TOKEN, token_header(session)));
+        }
+        match (&self.config.auth, &self.config.user) {
+            (AuthMethod::Basic, Some(user)) => {
+                Ok(builder.basic_auth(user, self.config.password.as_ref().map(Secret::expose)))
+            }
+            (AuthMethod::Bearer(token), _) => Ok(builder.bearer_auth(token.expose())),
+            _ => Ok(builder),
+        }
+    }
+
+    /// Send a request, turning transport failures and non-success statuses into errors.
+    /// GET and HEAD requests are retried according to `Config::retry`.
+    fn send(&self, builder: RequestBuilder) -> Result<Response, RedfishError> {
+        let mut req = builder.build()?;
+        let policy = self.config.retry;
+        let repeatable = matches!(*req.method(), Method::GET | Method::HEAD);
+        let mut attempt = 1;
+        loop {
+            let retry = if repeatable && attempt < policy.max_attempts {
+                req.try_clone()
+            } else {
+                None
+            };
+            match (self.execute(req), retry) {
+                (Err(e), Some(next)) if policy.should_retry(&e) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(attempt, error = %e, "retrying request");
+                    std::thread::sleep(policy.backoff(attempt));
+                    req = next;
+                    attempt += 1;
+                }
+                (result, _) => return result,
+            }
+        }
+    }
+
+    /// Send an already built request, for when headers need fixing up after `build`.
+    /// A request rejected with 401 while a session is active is sent once more
+    /// after logging in again, in case the session timed out.
+    fn execute(&self, req: Request) -> Result<Response, RedfishError> {
+        let relogin = if req.headers().contains_key(session::X_AUTH_TOKEN)
+            && self.session.lock().unwrap().is_some()
+        {
+            req.try_clone()
+        } else {
+            None
+        };
+        let mut res = self.dispatch(req)?;
+        if let (StatusCode::UNAUTHORIZED, Some(mut req)) = (res.status(), relogin) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("session rejected, logging in again");
+            let session = self.login()?;
+            req.headers_mut()
+                .insert(session::X_AUTH_TOKEN, token_header(&session));
+            res = self.dispatch(req)?;
+            if res.status() == StatusCode::UNAUTHORIZED {
+                return Err(RedfishError::Unauthorized {
+                    body: res.text().unwrap_or_default(),
+                });
+            }
+        }
+        let status = res.status();
+        if status == StatusCode::PRECONDITION_FAILED {
+            return Err(RedfishError::PreconditionFailed {
+                body: res.text().unwrap_or_default(),
+            });
+        }
+        if !status.is_success() {
+            return Err(RedfishError::UnexpectedStatus {
+                status: status.as_u16(),
+                body: res.text().unwrap_or_default(),
+            });
+        }
+        Ok(res)
+    }
+
+    /// Send a request once, returning whatever status the server answered with
+    fn dispatch(&self, req: Request) -> Result<Response, RedfishError> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "redfish_request",
+            host = %self.config.endpoint,
+            endpoint = %req.url().path()
+        )
+        .entered();
+        #[cfg(feature = "tracing")]
+        tracing::debug!(method = %req.method(), uri = %req.url(), "sending request");
+        let used_session = req.headers().contains_key(session::X_AUTH_TOKEN);
+        let res = self
+            .client
+            .execute(req)
+            .map_err(|e| self.transport_error(e))?;
+        let status = res.status();
+        if status == StatusCode::UNAUTHORIZED && used_session {
+            // The session expired or was revoked, later requests go back to Basic auth
+            self.session.lock().unwrap().take();
+        }
+        #[cfg(feature = "tracing")]
+        if !status.is_success() {
+            tracing::warn!(status = status.as_u16(), "request failed");
+        }
+        Ok(res)
+    }
+
+    /// Buffer a response body and deserialize it, naming `endpoint` if it does not fit `T`.
+    /// An empty body, such as a 204's, is read as `null`.
+    fn parse<T>(endpoint: &str, res: Response) -> Result<T, RedfishError>
+    where
+        T: DeserializeOwned,
+    {
+        let body = res.bytes()?;
+        let json: &[u8] = if body.is_empty() { b"null" } else { &body };
+        serde_json::from_slice(json).map_err(|source| RedfishError::Deserialize {
+            endpoint: endpoint.to_string(),
+            body: error::snippet(&body),
+            source,
+        })
+    }
+
+    /// The task monitor URI a server returns when it accepts a long-running request
+    fn location(res: &Response) -> Option<String> {
+        res.headers()
+            .get(LOCATION)
+            .and_then(|l| l.to_str().ok())
+            .map(|l| l.to_string())
+    }
+
+    fn transport_error(&self, e: reqwest::Error) -> RedfishError {
+        if let Some(pin) = &self.pin {
+            if let Some(found) = pin.take_mismatch() {
+                return RedfishError::CertificateMismatch {
+                    expected: pin.expected.clone(),
+                    found,
+                };
+            }
+        }
+        match (RedfishError::transport(e), self.config.timeout) {
+            (RedfishError::ReadTimeout(source), Some(after)) => RedfishError::Timeout {
+                after,
+                source: Some(source),
+            },
+            (err, _) => err,
+        }
+    }
+
+    pub fn get<T>(&self, api: &str) -> Result<T, RedfishError>
+    where
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        let res: T = Self::parse(api, self.send(self.request(Method::GET, api)?)?)?;
+        Ok(res)
+    }
+
+    /// Follow a link as found in `@odata.id`, e.g. `/redfish/v1/Systems/1/`.
+    /// Unlike `get`, the path may include the service root, which is then not
+    /// added a second time.
+    pub fn get_link<T>(&self, odata_id: &str) -> Result<T, RedfishError>
+    where
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        self.get(common::relative_path(odata_id))
+    }
+
+    /// Read the ETag of the resource at `api` for use with `If-Match`. The `ETag`
+    /// header is preferred, but some BMCs only provide the inline `@odata.etag`.
+    pub fn get_etag(&self, api: &str) -> Result<Option<String>, RedfishError> {
+        let res = self.send(self.request(Method::GET, api)?)?;
+        let header = res
+            .headers()
+            .get(ETAG)
+            .and_then(|e| e.to_str().ok())
+            .map(|e| e.to_string());
+        let resource: serde_json::Value = Self::parse(api, res)?;
+        Ok(common::resource_etag(header.as_deref(), &resource))
+    }
+
+    /// GET `api` without interpreting the response, keeping its status and
+    /// headers. Unlike `get`, a non-success status is returned rather than
+    /// turned into an error, and the request is not retried.
+    pub fn get_raw(&self, api: &str) -> Result<RawResponse, RedfishError> {
+        let res = self.dispatch(self.request(Method::GET, api)?.build()?)?;
+        let status = res.status().as_u16();
+        let headers = res.headers().clone();
+        let body = res.text()?;
+        Ok(RawResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    /// POST `body` to `api` and deserialize the response. Servers often answer
+    /// actions with an empty 204, which can be read as `()`, `Option<T>` or
+    /// `serde_json::Value`.
+    pub fn post<B, T>(&self, api: &str, body: &B) -> Result<T, RedfishError>
+    where
+        B: Serialize,
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        let res: T = Self::parse(api, self.send(self.request(Method::POST, api)?.json(body))?)?;
+        Ok(res)
+    }
+
+    /// Fetch any collection as a list of member links, to follow with `get`
+    pub fn get_collection(&self, api: &str) -> Result<common::Collection, RedfishError> {
+        let c: common::Collection = self.get(api)?;
+        Ok(c)
+    }
+
+    /// Fetch every member embedded in the collection at `api`, following
+    /// `Members@odata.nextLink` until the last page. Members are deserialized
+    /// as embedded, so use `common::ODataId` for collections that only link
+    /// to them. Fails with `RedfishError::Pagination` if a page repeats or
+    /// more than `pagination::MAX_PAGES` are linked.
+    pub fn get_all<T>(&self, api: &str) -> Result<Vec<T>, RedfishError>
+    where
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        let mut guard = pagination::PageGuard::new(api);
+        let mut members = Vec::new();
+        let mut next = Some(api.to_string());
+        while let Some(url) = next {
+            guard.visit(&url)?;
+            let page: pagination::Page<T> = self.get(&url)?;
+            members.extend(page.members);
+            next = page
+                .next_link
+                .map(|l| common::relative_path(&l).to_string());
+        }
+        Ok(members)
+    }
+
+    /// Lazily walk every member of the collection at `collection_path`, following
+    /// `Members@odata.nextLink` as pages run out and fetching each member as it
+    /// is reached
+    pub fn stream_members<T>(&self, collection_path: &str) -> pagination::Members<'_, T>
+    where
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        pagination::Members::new(self, collection_path)
+    }
+
+    /// GET a resource that may legitimately be absent, mapping a 404 to `None`
+    fn get_optional<T>(&self, api: &str) -> Result<Option<T>, RedfishError>
+    where
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        match self.get(api) {
+            Ok(t) => Ok(Some(t)),
+            Err(e) if e.status() == Some(StatusCode::NOT_FOUND) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Fetch the sole member of the collection at `collection_path`, for the common
+    /// case of a host with a single manager, chassis or system. Fails with
+    /// `RedfishError::UnexpectedMemberCount` rather than guessing when there
+    /// are zero or several.
+    pub fn get_single<T>(&self, collection_path: &str) -> Result<T, RedfishError>
+    where
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        let page: common::Collection = self.get(collection_path)?;
+        let count = page.members_count.unwrap_or(page.members.len() as u64);
+        match page.members.as_slice() {
+            [member] if count == 1 && page.next_link.is_none() => self.get(member.path()),
+            _ => Err(RedfishError::UnexpectedMemberCount {
+                collection: collection_path.to_string(),
+                count,
+            }),
+        }
+    }
+
+    /// PATCH `body` to `api` and deserialize the response, which may be the
+    /// updated resource or, as with POST, an empty 200 or 204
+    pub fn patch<B, T>(&self, api: &str, body: &B) -> Result<T, RedfishError>
+    where
+        B: Serialize,
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        let res: T = Self::parse(api, self.patch_response(api, body, None)?)?;
+        Ok(res)
+    }
+
+    /// PATCH `body` to `api` only if the resource still has `etag`, as read
+    /// with `get_etag`. Fails with `RedfishError::PreconditionFailed` if it
+    /// has been modified since.
+    pub fn patch_with_etag<B, T>(&self, api: &str, body: &B, etag: &str) -> Result<T, RedfishError>
+    where
+        B: Serialize,
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        let res: T = Self::parse(api, self.patch_response(api, body, Some(etag))?)?;
+        Ok(res)
+    }
+
+    /// DELETE the resource at `api`, discarding any response body
+    pub fn delete(&self, api: &str) -> Result<(), RedfishError> {
+        self.send(self.request(Method::DELETE, api)?)?;
+        Ok(())
+    }
+
+    /// PATCH a resource, discarding any response body
+    fn patch_json<B>(&self, api: &str, body: &B) -> Result<(), RedfishError>
+    where
+        B: Serialize,
+    {
+        self.patch_response(api, body, None)?;
+        Ok(())
+    }
+
+    /// Send a PATCH with `If-Match: etag`. Without an ETag, a server that
+    /// answers 428 Precondition Required is asked for the resource's ETag and
+    /// the PATCH is sent again with it.
+    fn patch_response<B>(
+        &self,
+        api: &str,
+        body: &B,
+        etag: Option<&str>,
+    ) -> Result<Response, RedfishError>
+    where
+        B: Serialize,
+    {
+        let mut builder = self.request(Method::PATCH, api)?.json(body);
+        if let Some(etag) = etag {
+            builder = builder.header(IF_MATCH, etag);
+        }
+        match self.send(builder) {
+            Err(e) if etag.is_none() && e.status() == Some(StatusCode::PRECONDITION_REQUIRED) => {
+                match self.get_etag(api)? {
+                    Some(etag) => self.patch_response(api, body, Some(&etag)),
+                    None => Err(e),
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Apply every PATCH queued in `tx`, reverting the ones already applied if
+    /// a later one fails. See `transaction::Transaction` for the guarantees
+    /// this can and cannot give.
+    pub fn transaction(
+        &self,
+        tx: &transaction::Transaction,
+    ) -> Result<(), transaction::TransactionError> {
+        let mut applied: Vec<(&str, serde_json::Value)> = Vec::new();
+        for (api, body) in &tx.operations {
+            let result = self.get::<serde_json::Value>(api).and_then(|current| {
+                let prior = transaction::snapshot(&current, body);
+                self.patch_json(api, body).map(|_| prior)
+            });
+            match result {
+                Ok(prior) => applied.push((api.as_str(), prior)),
+                Err(e) => {
+                    let rollback_failures = applied
+                        .iter()
+                        .rev()
+                        .filter_map(|(api, prior)| {
+                            self.patch_json(api, prior)
+                                .err()
+                                .map(|e| (api.to_string(), e))
+                        })
+                        .collect();
+                    return Err(transaction::TransactionError {
+                        failed: api.clone(),
+                        source: e,
+                        rollback_failures,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Create a session with the configured credentials. Until `logout`, requests
+    /// authenticate with the session token instead of Basic auth, saving BMCs
+    /// such as iLO from checking the password on every call. If the session
+    /// expires, the next request logs in again and is retried; should that
+    /// fail, the client falls back to Basic auth.
+    pub fn login(&self) -> Result<session::SessionToken, RedfishError> {
+        let (user, password) = match (&self.config.user, &self.config.password) {
+            (Some(user), Some(password)) => (user, password),
+            _ => {
+                return Err(RedfishError::InvalidConfig(
+                    "login needs a user and password".to_string(),
+                ));
+            }
+        };
+        let body = session::SessionCredentials {
+            user_name: user,
+            password: password.expose(),
+        };
+        self.session.lock().unwrap().take();
+        let res = self.send(
+            self.request(Method::POST, "SessionService/Sessions/")?
+                .json(&body),
+        )?;
+        let token = res
+            .headers()
+            .get(session::X_AUTH_TOKEN)
+            .and_then(|t| t.to_str().ok())
+            .ok_or_else(|| RedfishError::NotSupported("session X-Auth-Token".to_string()))?;
+        let location = Self::location(&res).map(|l| common::relative_path(&l).to_string());
+        *self.session.lock().unwrap() = Some(session::SessionToken {
+            token: Secret::new(token),
+            location: location.clone(),
+        });
+        Ok(session::SessionToken {
+            token: Secret::new(token),
+            location,
+        })
+    }
+
+    /// Delete the active session, if any, and go back to Basic auth. Dropping
+    /// the client does this as well, but any error is lost there.
+    pub fn logout(&self) -> Result<(), RedfishError> {
+        // Ended locally first, so an expired session is not logged back into
+        let session = match self.session.lock().unwrap().take() {
+            Some(session) => session,
+            None => return Ok(()),
+        };
+        match &session.location {
+            Some(location) => {
+                let req = self
+                    .request(Method::DELETE, location)?
+                    .header(session::X_AUTH_TOKEN, token_header(&session));
+                self.send(req)?;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// List the sessions open on the BMC, including ones held by other clients
+    pub fn get_sessions(&self) -> Result<session::SessionCollection, RedfishError> {
+        let url = "SessionService/Sessions/";
+        let s: session::SessionCollection = self.get(url)?;
+        Ok(s)
+    }
+
+    /// End a session by id, e.g. one left behind by a client that never logged out
+    pub fn delete_session(&self, session_id: &str) -> Result<(), RedfishError> {
+        let url = format!("SessionService/Sessions/{}/", session_id);
+        self.delete(&url)
+    }
+
+    /// End every session on the BMC, freeing its session slots, and then this
+    /// client's own with `logout`. Sessions that end on their own meanwhile
+    /// are skipped.
+    pub fn close_all_sessions(&self) -> Result<(), RedfishError> {
+        let own = self
+            .session
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|s| s.location.clone());
+        let own = own.as_deref().map(|l| l.trim_end_matches('/'));
+        for member in self.get_sessions()?.members {
+            let path = member.path();
+            if Some(path.trim_end_matches('/')) == own {
+                continue;
+            }
+            match self.delete(path) {
+                Err(e) if e.status() == Some(StatusCode::NOT_FOUND) => {}
+                result => result?,
+            }
+        }
+        self.logout()
+    }
+
+    /// Query the service root, whose links locate the systems, chassis and
+    /// managers collections on hosts that do not follow the usual layout
+    pub fn get_service_root(&self) -> Result<service_root::ServiceRoot, RedfishError> {
+        let url = "";
+        let r: service_root::ServiceRoot = self.get(url)?;
+        Ok(r)
+    }
+
+    /// Fetch the service's OData `$metadata` CSDL document as raw XML
+    pub fn get_metadata(&self) -> Result<String, RedfishError> {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static("application/xml"));
+        let metadata = self
+            .send(self.request(Method::GET, "$metadata")?.headers(headers))?
+            .text()?;
+        Ok(metadata)
+    }
+
+    /// POST a Redfish action and return the task monitor URI if the server started one
+    pub fn post_action<B>(&self, api: &str, body: &B) -> Result<Option<String>, RedfishError>
+    where
+        B: Serialize,
+    {
+        let res = self.send(self.request(Method::POST, api)?.json(body))?;
+        Ok(Self::location(&res))
+    }
+
+    /// Query which external directories (LDAP, Active Directory) the BMC
+    /// authenticates accounts against
+    pub fn get_account_service_auth(&self) -> Result<accounts::AccountServiceAuth, RedfishError> {
+        let url = "AccountService/";
+        let a: accounts::AccountServiceAuth = self.get(url)?;
+        Ok(a)
+    }
+
+    /// List the local BMC user accounts
+    pub fn get_accounts(&self) -> Result<accounts::ManagerAccountCollection, RedfishError> {
+        let url = "AccountService/Accounts/";
+        let a: accounts::ManagerAccountCollection = self.get(url)?;
+        Ok(a)
+    }
+
+    /// Query one local BMC user account
+    pub fn get_account(&self, account_id: &str) -> Result<accounts::ManagerAccount, RedfishError> {
+        let url = format!("AccountService/Accounts/{}/", account_id);
+        let a: accounts::ManagerAccount = self.get(&url)?;
+        Ok(a)
+    }
+
+    /// Create a local BMC user account, returning the new account's location
+    /// when the service reports one
+    pub fn create_account(
+        &self,
+        username: &str,
+        password: impl Into<Secret>,
+        role_id: accounts::RoleId,
+    ) -> Result<Option<String>, RedfishError> {
+        let url = "AccountService/Accounts/";
+        let body = accounts::NewAccount {
+            user_name: username,
+            password: password.into(),
+            role_id,
+        };
+        self.post_action(url, &body)
+    }
+
+    /// Delete a local BMC user account
+    pub fn delete_account(&self, account_id: &str) -> Result<(), RedfishError> {
+        let url = format!("AccountService/Accounts/{}/", account_id);
+        self.delete(&url)
+    }
+
+    /// List the event subscriptions registered with the `EventService`
+    pub fn get_event_subscriptions(
+        &self,
+    ) -> Result<events::EventSubscriptionCollection, RedfishError> {
+        let url = "EventService/Subscriptions/";
+        let s: events::EventSubscriptionCollection = self.get(url)?;
+        Ok(s)
+    }
+
+    /// Ask the BMC to push events of `event_types` to `destination_url`, a
+    /// webhook receiver reachable from the BMC, returning the new
+    /// subscription's location when the service reports one
+    pub fn subscribe(
+        &self,
+        destination_url: &str,
+        event_types: &[events::EventType],
+    ) -> Result<Option<String>, RedfishError> {
+        let url = "EventService/Subscriptions/";
+        let body = events::NewSubscription {
+            destination: destination_url,
+            event_types,
+            protocol: "Redfish",
+        };
+        self.post_action(url, &body)
+    }
+
+    /// Remove an event subscription, stopping the events it pushes
+    pub fn unsubscribe(&self, subscription_id: &str) -> Result<(), RedfishError> {
+        let url = format!("EventService/Subscriptions/{}/", subscription_id);
+        self.delete(&url)
+    }
+
+    /// Follow the `EventService` Server-Sent Events stream, receiving events
+    /// as they happen without running a webhook receiver for `subscribe`
+    pub fn stream_events(&self) -> events::EventStream<'_> {
+        events::EventStream::new(self)
+    }
+
+    /// Open the SSE stream, asking to resume after `last_event_id`
+    fn open_event_stream(&self, last_event_id: Option<&str>) -> Result<Response, RedfishError> {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static("text/event-stream"));
+        if let Some(id) = last_event_id.and_then(|id| HeaderValue::from_str(id).ok()) {
+            headers.insert("Last-Event-ID", id);
+        }
+        self.send(
+            self.untimed_request(Method::GET, events::SSE_PATH)?
+                .headers(headers),
+        )
+    }
+
+    /// List the tasks the `TaskService` is tracking
+    pub fn get_tasks(&self) -> Result<task::TaskCollection, RedfishError> {
+        let url = "TaskService/Tasks/";
+        let t: task::TaskCollection = self.get(url)?;
+        Ok(t)
+    }
+
+    /// Query the state and progress of one task
+    pub fn get_task(&self, task_id: &str) -> Result<task::Task, RedfishError> {
+        let url = format!("TaskService/Tasks/{}/", task_id);
+        let t: task::Task = self.get(&url)?;
+        Ok(t)
+    }
+
+    /// Query a task every `interval`, blocking until it has completed, failed
+    /// or been killed, and return its final state. Fails with
+    /// `RedfishError::Timeout` if the task is still running after `timeout`.
+    pub fn poll_task_until_complete(
+        &self,
+        task_id: &str,
+        interval: Duration,
+        timeout: Duration,
+    ) -> Result<task::Task, RedfishError> {
+        let started = std::time::Instant::now();
+        loop {
+            let t = self.get_task(task_id)?;
+            if t.task_state.is_finished() {
+                return Ok(t);
+            }
+            if started.elapsed() >= timeout {
+                return Err(RedfishError::Timeout {
+                    after: timeout,
+                    source: None,
+                });
+            }
+            std::thread::sleep(interval.min(timeout.saturating_sub(started.elapsed())));
+        }
+    }
+
+    /// List the registries (message, BIOS attribute, event) the service publishes
+    pub fn get_registries(&self) -> Result<registry::Registries, RedfishError> {
+        let url = "Registries/";
+        let r: registry::Registries = self.get(url)?;
+        Ok(r)
+    }
+
+    /// Fetch and parse the registry `registry_id`, e.g. `Base.1.15.0` as a
+    /// `registry::MessageRegistry`. Only a copy hosted by the service can be read;
+    /// a registry that is only published elsewhere fails with `NotSupported`, as
+    /// this client's credentials and certificate pin are for the BMC alone.
+    pub fn get_registry<T>(&self, registry_id: &str) -> Result<T, RedfishError>
+    where
+        T: DeserializeOwned + ::std::fmt::Debug,
+    {
+        let url = format!("Registries/{}/", registry_id);
+        let file: registry::MessageRegistryFile = self.get(&url)?;
+        match file.preferred_location() {
+            Some(registry::RegistrySource::Hosted(uri)) => self.get(common::relative_path(uri)),
+            Some(registry::RegistrySource::Published(uri)) => Err(RedfishError::NotSupported(
+                format!("registry {} is only published at {}", registry_id, uri),
+            )),
+            None => Err(RedfishError::NotSupported(format!(
+                "no location for registry {}",
+                registry_id
+            ))),
+        }
+    }
+
+    /// Query the update service and the push URIs it accepts firmware on
+    pub fn get_update_service(&self) -> Result<update::UpdateService, RedfishError> {
+        let url = "UpdateService/";
+        let u: update::UpdateService = self.get(url)?;
+        Ok(u)
+    }
+
+    /// List the firmware components installed on the host
+    pub fn get_firmware_inventory(&self) -> Result<update::FirmwareInventory, RedfishError> {
+        let url = "UpdateService/FirmwareInventory/";
+        let f: update::FirmwareInventory = self.get(url)?;
+        Ok(f)
+    }
+
+    /// Query the version of one firmware component and whether it can be updated
+    pub fn get_firmware_component(
+        &self,
+        component_id: &str,
+    ) -> Result<update::SoftwareInventory, RedfishError> {
+        let url = format!("UpdateService/FirmwareInventory/{}/", component_id);
+        let f: update::SoftwareInventory = self.get(&url)?;
+        Ok(f)
+    }
+
+    /// Upload a firmware image to the update service's push URI as a multipart
+    /// form, for BMCs that will not fetch an image through `SimpleUpdate`. Images
+    /// given by path are streamed from disk rather than read into memory. Returns
+    /// the task monitor URI if the server started one.
+    pub fn push_firmware<I>(
+        &self,
+        image: I,
+        targets: &[&str],
+        apply_time: Option<update::ApplyTime>,
+    ) -> Result<Option<String>, RedfishError>
+    where
+        I: Into<update::FirmwareImage>,
+    {
+        let service = self.get_update_service()?;
+        let push_uri = service
+            .push_uri()
+            .map(|uri| common::relative_path(uri).to_string())
+            .ok_or_else(|| RedfishError::NotSupported("UpdateService HttpPushUri".to_string()))?;
+        let parameters = update::UpdateParameters {
+            targets: targets.iter().map(|t| t.to_string()).collect(),
+            apply_time,
+        };
+        let parameters =
+            serde_json::to_string(&parameters).map_err(|e| RedfishError::InvalidValue {
+                property: "UpdateParameters".to_string(),
+                value: e.to_string(),
+                allowed: Vec::new(),
+            })?;
+        let parameters = multipart::Part::text(parameters).mime_str("application/json")?;
+        let file = match image.into() {
+            update::FirmwareImage::Path(path) => {
+                let file_name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| "firmware.bin".to_string());
+                let file = std::fs::File::open(&path)?;
+                multipart::Part::reader(file).file_name(file_name)
+            }
+            update::FirmwareImage::Bytes { file_name, data } => {
+                multipart::Part::bytes(data).file_name(file_name)
+            }
+        };
+        let form = multipart::Form::new()
+            .part("UpdateParameters", parameters)
+            .part("UpdateFile", file.mime_str("application/octet-stream")?);
+        // The multipart Content-Type is appended after the default JSON one, so
+        // replace it to leave the BMC a single, correct header
+        let mut req = self
+            .request(Method::POST, &push_uri)
+            .multipart(form)
+            .build()?;
+        if let Some(content_type) = req.headers().get_all(CONTENT_TYPE).iter().last().cloned() {
+            req.headers_mut().insert(CONTENT_TYPE, content_type);
+        }
+        let res = self.execute(req)?;
+        Ok(Self::location(&res))
+    }
+
+    pub fn get_array_controller(
+        &self,
+        system_id: &str,
+        controller_id: u64,
+    ) -> Result<storage::ArrayController, RedfishError> {
+        let url = format!(
+            "Systems/{}/SmartStorage/ArrayControllers/{}/",
+            system_id, controller_id
+        );
+        let s: storage::ArrayController = self.get(&url)?;
+        Ok(s)
+    }
+    pub fn get_array_controller_for_system_1(
+        &self,
+        controller_id: u64,
+    ) -> Result<storage::ArrayController, RedfishError> {
+        self.get_array_controller("1", controller_id)
+    }
+    pub fn get_array_controllers(
+        &self,
+        system_id: &str,
+    ) -> Result<storage::ArrayControllers, RedfishError> {
+        let url = format!("Systems/{}/SmartStorage/ArrayControllers/", system_id);
+        let s: storage::ArrayControllers = self.get(&url)?;
+        Ok(s)
+    }
+    pub fn get_array_controllers_for_system_1(
+        &self,
+    ) -> Result<storage::ArrayControllers, RedfishError> {
+        self.get_array_controllers("1")
+    }
+
+    /// Query the manager status from the server, of the first manager for
+    /// hosts with several
+    pub fn get_manager_status(&self) -> Result<manager::Manager, RedfishError> {
+        let url = self.first_manager_path()?;
+        let m: manager::Manager = self.get(&url)?;
+        Ok(m)
+    }
+
+    /// List the managers of the host
+    pub fn get_managers(&self) -> Result<manager::ManagerCollection, RedfishError> {
+        let url = "Managers/";
+        let m: manager::ManagerCollection = self.get(url)?;
+        Ok(m)
+    }
+
+    /// Query the status of a single manager, e.g. `iDRAC.Embedded.1`
+    pub fn get_manager(&self, manager_id: &str) -> Result<manager::Manager, RedfishError> {
+        let url = format!("Managers/{}/", manager_id);
+        let m: manager::Manager = self.get(&url)?;
+        Ok(m)
+    }
+
+    /// Resolve the first member of the `Managers` collection, relative to the service root
+    fn first_manager_path(&self) -> Result<String, RedfishError> {
+        let managers: common::Collection = self.get("Managers/")?;
+        managers
+            .members
+            .first()
+            .map(|m| m.path().to_string())
+            .ok_or_else(|| RedfishError::NotSupported("no managers found".to_string()))
+    }
+
+    /// Query the firmware version the BMC is running, e.g. `iLO 4 v2.55`
+    pub fn bmc_firmware_version(&self) -> Result<String, RedfishError> {
+        let manager: serde_json::Value = self.get(&self.first_manager_path()?)?;
+        manager
+            .get("FirmwareVersion")
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_string())
+            .ok_or_else(|| RedfishError::NotSupported("manager FirmwareVersion".to_string()))
+    }
+
+    /// Resolve the manager's network protocol resource, which iLO 4 names `NetworkService`
+    fn network_protocol_path(&self) -> Result<String, RedfishError> {
+        let manager_path = self.first_manager_path()?;
+        let manager: serde_json::Value = self.get(&manager_path)?;
+        let path = match manager["NetworkProtocol"]["@odata.id"].as_str() {
+            Some(link) => common::relative_path(link).to_string(),
+            None => format!("{}/NetworkProtocol/", manager_path.trim_end_matches('/')),
+        };
+        Ok(path)
+    }
+
+    /// Query the network services exposed by the manager
+    pub fn get_network_protocol(&self) -> Result<manager::NetworkProtocol, RedfishError> {
+        let n: manager::NetworkProtocol = self.get(&self.network_protocol_path()?)?;
+        Ok(n)
+    }
+
+    /// Query whether IPMI-over-LAN is enabled on the manager
+    pub fn get_ipmi_lan_enabled(&self) -> Result<bool, RedfishError> {
+        self.get_network_protocol()?
+            .ipmi
+            .and_then(|p| p.protocol_enabled)
+            .ok_or_else(|| RedfishError::NotSupported("IPMI-over-LAN".to_string()))
+    }
+
+    /// Enable or disable IPMI-over-LAN on the manager
+    pub fn set_ipmi_lan_enabled(&self, enabled: bool) -> Result<(), RedfishError> {
+        let body = serde_json::json!({ "IPMI": { "ProtocolEnabled": enabled } });
+        self.patch_json(&self.network_protocol_path()?, &body)
+    }
+
+    /// Check whether the resource at `resource_path` advertises `action_name`,
+    /// so callers can adapt to a host's capabilities instead of guessing by vendor
+    pub fn supports_action(
+        &self,
+        resource_path: &str,
+        action_name: &str,
+    ) -> Result<bool, RedfishError> {
+        let resource: serde_json::Value = self.get(resource_path)?;
+        Ok(common::has_action(&resource, action_name))
+    }
+
+    /// Query one of the manager's virtual media drives, e.g. `CD`
+    pub fn get_virtual_media(
+        &self,
+        manager_id: &str,
+        media_id: &str,
+    ) -> Result<virtual_media::VirtualMedia, RedfishError> {
+        let url = format!("Managers/{}/VirtualMedia/{}/", manager_id, media_id);
+        let v: virtual_media::VirtualMedia = self.get(&url)?;
+        Ok(v)
+    }
+
+    /// Attach the image at `image_url` to a virtual media drive, read-only.
+    /// Uses `VirtualMedia.InsertMedia` where the drive advertises it and
+    /// PATCHes `Image` otherwise.
+    pub fn insert_media(
+        &self,
+        manager_id: &str,
+        media_id: &str,
+        image_url: &str,
+    ) -> Result<(), RedfishError> {
+        let url = format!("Managers/{}/VirtualMedia/{}/", manager_id, media_id);
+        let resource: serde_json::Value = self.get(&url)?;
+        if common::has_action(&resource, "VirtualMedia.InsertMedia") {
+            let body = virtual_media::InsertMedia {
+                image: image_url,
+                inserted: true,
+                write_protected: true,
+            };
+            self.post_action(&format!("{}Actions/VirtualMedia.InsertMedia/", url), &body)?;
+        } else {
+            let body = virtual_media::MediaImage {
+                image: Some(image_url),
+            };
+            self.patch_json(&url, &body)?;
+        }
+        Ok(())
+    }
+
+    /// Detach whatever image is attached to a virtual media drive, using
+    /// `VirtualMedia.EjectMedia` where the drive advertises it and clearing
+    /// `Image` otherwise
+    pub fn eject_media(&self, manager_id: &str, media_id: &str) -> Result<(), RedfishError> {
+        let url = format!("Managers/{}/VirtualMedia/{}/", manager_id, media_id);
+        let resource: serde_json::Value = self.get(&url)?;
+        if common::has_action(&resource, "VirtualMedia.EjectMedia") {
+            self.post_action(
+                &format!("{}Actions/VirtualMedia.EjectMedia/", url),
+                &serde_json::json!({}),
+            )?;
+        } else {
+            self.patch_json(&url, &virtual_media::MediaImage { image: None })?;
+        }
+        Ok(())
+    }
+
+    /// Query the iLO Federation groups the manager is a member of
+    pub fn get_federation_groups(
+        &self,
+        manager_id: &str,
+    ) -> Result<manager::FederationGroups, RedfishError> {
+        let url = format!("Managers/{}/FederationGroups/", manager_id);
+        let f: manager::FederationGroups = self.get(&url)?;
+        Ok(f)
+    }
+
+    /// Query a single iLO Federation group by name
+    pub fn get_federation_group(
+        &self,
+        manager_id: &str,
+        group_name: &str,
+    ) -> Result<manager::FederationGroup, RedfishError> {
+        let url = format!("Managers/{}/FederationGroups/{}/", manager_id, group_name);
+        let f: manager::FederationGroup = self.get(&url)?;
+        Ok(f)
+    }
+
+    /// Reboot a manager, e.g. to recover a BMC that stopped responding, and
+    /// return the task monitor URI if the server started one. A request that
+    /// was sent but got no answer is treated as success.
+    pub fn reset_manager(
+        &self,
+        manager_id: &str,
+        reset_type: manager::ManagerResetType,
+    ) -> Result<Option<String>, RedfishError> {
+        let url = format!("Managers/{}/Actions/Manager.Reset/", manager_id);
+        let body = manager::ManagerReset { reset_type };
+        match self.post_action(&url, &body) {
+            Ok(location) => Ok(location),
+            Err(e) if e.no_response() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reset the manager to its factory defaults.
+    ///
+    /// **This is destructive.** The BMC reboots and the connection is lost; with
+    /// `ResetAll` or `PreserveNetwork` the accounts are wiped, so the credentials
+    /// in this client stop working, and with `ResetAll` the BMC may also come
+    /// back on a different address. Only use this when decommissioning a host or
+    /// when out-of-band access to it is otherwise assured.
+    ///
+    /// As with `reset_manager`, a request that was sent but got no answer is
+    /// treated as success.
+    pub fn reset_manager_to_defaults(
+        &self,
+        manager_id: &str,
+        reset_type: manager::ResetToDefaultsType,
+    ) -> Result<(), RedfishError> {
+        let url = format!("Managers/{}/Actions/Manager.ResetToDefaults/", manager_id);
+        let body = manager::ResetToDefaults { reset_type };
+        match self.post_action(&url, &body) {
+            Ok(_) => Ok(()),
+            Err(e) if e.no_response() => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Ask the manager to collect a diagnostic data dump. The dump is collected
+    /// asynchronously, so the returned task monitor URI should be polled before
+    /// downloading the resulting entry with `download_dump`.
+    pub fn collect_diagnostic_data(
+        &self,
+        diag_type: manager::DiagnosticDataType,
+    ) -> Result<Option<String>, RedfishError> {
+        let url = "Managers/1/LogServices/Dump/Actions/LogService.CollectDiagnosticData/";
+        let body = manager::CollectDiagnosticData {
+            diagnostic_data_type: diag_type,
+        };
+        self.post_action(url, &body)
+    }
+
+    /// Stream the attachment of a dump log entry into `writer`, returning the
+    /// number of bytes written
+    pub fn download_dump<W>(&self, entry_id: &str, writer: &mut W) -> Result<u64, RedfishError>
+    where
+        W: ::std::io::Write + ?Sized,
+    {
+        let url = format!(
+            "Managers/1/LogServices/Dump/Entries/{}/attachment",
+            entry_id
+        );
+        let written = self
+            .send(self.request(Method::GET, &url)?)?
+            .copy_to(writer)?;
+        Ok(written)
+    }
+
+    /// The first manager's first serial interface, if it has any
+    fn serial_interface(&self) -> Result<Option<manager::SerialInterface>, RedfishError> {
+        let url = format!(
+            "{}/SerialInterfaces/",
+            self.first_manager_path()?.trim_end_matches('/')
+        );
+        let interfaces: Option<common::Collection> = self.get_optional(&url)?;
+        match interfaces.and_then(|i| i.members.into_iter().next()) {
+            Some(member) => Ok(Some(self.get(member.path())?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Query serial console redirection, from the manager's serial interface when
+    /// it has one and otherwise from the vendor BIOS attributes of `system_id`
+    pub fn get_serial_console_config(
+        &self,
+        system_id: &str,
+    ) -> Result<manager::SerialConsoleConfig, RedfishError> {
+        if let Some(interface) = self.serial_interface()? {
+            return Ok(interface.console_config());
+        }
+        self.get_bios_for_system(system_id)?
+            .serial_console()
+            .ok_or_else(|| RedfishError::NotSupported("serial console redirection".to_string()))
+    }
+
+    /// Configure serial console redirection. The baud rate and flow control are
+    /// checked against what the host allows before anything is sent. Changes made
+    /// through BIOS attributes take effect on the next reboot.
+    pub fn set_serial_console(
+        &self,
+        system_id: &str,
+        enabled: bool,
+        bit_rate: u32,
+        flow_control: manager::FlowControl,
+    ) -> Result<(), RedfishError> {
+        if let Some(interface) = self.serial_interface()? {
+            interface.validate(bit_rate, flow_control)?;
+            let body = serde_json::json!({
+                "BitRate": bit_rate.to_string(),
+                "FlowControl": flow_control,
+                "InterfaceEnabled": enabled,
+            });
+            return self.patch_json(common::relative_path(&interface.odata.odata_id), &body);
+        }
+        let bios = self.get_bios_for_system(system_id)?;
+        let attributes = bios.serial_console_attributes(enabled, bit_rate, flow_control)?;
+        let body = serde_json::json!({ "Attributes": attributes });
+        self.patch_json(&bios.settings_path(), &body)
+    }
+
+    /// Query a computer system's inventory and status: its make, model and
+    /// serial number, BIOS version, power state, and processor and memory
+    /// summaries. With the `time` feature enabled the last reset time is also
+    /// available parsed, along with the derived uptime.
+    pub fn get_system(&self, system_id: &str) -> Result<system::ComputerSystem, RedfishError> {
+        let url = format!("Systems/{}/", system_id);
+        let s: system::ComputerSystem = self.get(&url)?;
+        Ok(s)
+    }
+
+    /// Query the first member of the `Systems` collection, for hosts with one
+    /// system whose id is not known ahead of time
+    pub fn get_first_system(&self) -> Result<system::ComputerSystem, RedfishError> {
+        let systems: common::Collection = self.get("Systems/")?;
+        let url = systems
+            .members
+            .first()
+            .map(|m| m.path().to_string())
+            .ok_or_else(|| RedfishError::NotSupported("no systems found".to_string()))?;
+        let s: system::ComputerSystem = self.get(&url)?;
+        Ok(s)
+    }
+
+    /// List the computer systems of the host, e.g. one per node of a blade chassis
+    pub fn get_systems(&self) -> Result<system::SystemCollection, RedfishError> {
+        let url = "Systems/";
+        let s: system::SystemCollection = self.get(url)?;
+        Ok(s)
+    }
+
+    /// Query the system's power state and what it does when power is restored.
+    /// The delay is read from the standard `PowerOnDelaySeconds` when the host
+    /// has it, otherwise from the vendor BIOS attribute.
+    pub fn get_power_on_policy(
+        &self,
+        system_id: &str,
+    ) -> Result<system::PowerOnPolicy, RedfishError> {
+        let s = self.get_system(system_id)?;
+        let delay = match s.power_on_delay_seconds {
+            Some(seconds) => Some(system::PowerOnDelay::from_seconds(seconds)?),
+            None => self.get_bios_for_system(system_id)?.power_on_delay(),
+        };
+        Ok(system::PowerOnPolicy {
+            power_state: s.power_state,
+            power_restore_policy: s.power_restore_policy,
+            delay,
+        })
+    }
+
+    /// Set how long the system waits before powering on, for staggering a rack's
+    /// startup. Hosts with `PowerOnDelaySeconds` are updated directly; otherwise
+    /// the vendor BIOS attribute is staged and takes effect on the next reboot.
+    pub fn set_power_on_delay(
+        &self,
+        system_id: &str,
+        delay: system::PowerOnDelay,
+    ) -> Result<(), RedfishError> {
+        let s = self.get_system(system_id)?;
+        if let (Some(_), system::PowerOnDelay::Seconds(seconds)) = (s.power_on_delay_seconds, delay)
+        {
+            let url = format!("Systems/{}/", system_id);
+            let body = serde_json::json!({ "PowerOnDelaySeconds": seconds });
+            return self.patch_json(&url, &body);
+        }
+        let bios = self.get_bios_for_system(system_id)?;
+        let body = serde_json::json!({ "Attributes": bios.power_on_delay_attributes(delay)? });
+        self.patch_json(&bios.settings_path(), &body)
+    }
+
+    /// Query a system's UEFI Secure Boot settings
+    pub fn get_secure_boot(
+        &self,
+        system_id: &str,
+    ) -> Result<secure_boot::SecureBoot, RedfishError> {
+        let url = format!("Systems/{}/SecureBoot/", system_id);
+        let s: secure_boot::SecureBoot = self.get(&url)?;
+        Ok(s)
+    }
+
+    /// Enable or disable UEFI Secure Boot. Most systems only apply the change
+    /// on their next reboot, until which `SecureBootCurrentBoot` is unchanged.
+    pub fn set_secure_boot(&self, system_id: &str, enabled: bool) -> Result<(), RedfishError> {
+        let url = format!("Systems/{}/SecureBoot/", system_id);
+        let body = secure_boot::SetSecureBoot {
+            secure_boot_enable: enabled,
+        };
+        self.patch_json(&url, &body)
+    }
+
+    /// Query whether a system is powered on, without fetching its power metrics
+    pub fn get_system_power_state(
+        &self,
+        system_id: &str,
+    ) -> Result<system::PowerState, RedfishError> {
+        self.get_system(system_id)?
+            .power_state
+            .ok_or_else(|| RedfishError::NotSupported("PowerState".to_string()))
+    }
+
+    /// Power on, off or restart a system
+    pub fn reset_system(
+        &self,
+        system_id: &str,
+        reset_type: system::ResetType,
+    ) -> Result<(), RedfishError> {
+        let url = format!("Systems/{}/Actions/ComputerSystem.Reset/", system_id);
+        let body = system::Reset { reset_type };
+        let _: serde_json::Value = self.post(&url, &body)?;
+        Ok(())
+    }
+
+    /// Query the system's boot source override
+    pub fn get_boot_options(&self, system_id: &str) -> Result<boot::BootOptions, RedfishError> {
+        let url = format!("Systems/{}/", system_id);
+        let b: boot::SystemBoot = self.get(&url)?;
+        Ok(b.boot)
+    }
+
+    /// Boot from `target` instead of the normal boot order, for the next boot
+    /// only or until changed depending on `enabled`
+    pub fn set_boot_override(
+        &self,
+        system_id: &str,
+        target: boot::BootSource,
+        enabled: boot::BootEnabled,
+    ) -> Result<(), RedfishError> {
+        let url = format!("Systems/{}/", system_id);
+        let body = boot::SetBoot {
+            boot: boot::BootOverride {
+                boot_source_override_enabled: enabled,
+                boot_source_override_target: target,
+            },
+        };
+        self.patch_json(&url, &body)
+    }
+
+    pub fn get_log_service(
+        &self,
+        system_id: &str,
+        log_id: &str,
+    ) -> Result<log::LogService, RedfishError> {
+        let url = format!("Systems/{}/LogServices/{}/", system_id, log_id);
+        let l: log::LogService = self.get(&url)?;
+        Ok(l)
+    }
+
+    /// Read every entry of a system log such as the SEL, following
+    /// `Members@odata.nextLink` across pages
+    pub fn get_log_entries(
+        &self,
+        system_id: &str,
+        log_id: &str,
+    ) -> Result<Vec<log::LogEntry>, RedfishError> {
+        let url = format!("Systems/{}/LogServices/{}/Entries/", system_id, log_id);
+        self.get_all(&url)
+    }
+
+    /// Read the entries of a system log logged after `since`, following
+    /// `Members@odata.nextLink` across pages, for polling a log incrementally
+    #[cfg(feature = "time")]
+    pub fn get_log_entries_since(
+        &self,
+        system_id: &str,
+        log_id: &str,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<log::LogEntry>, RedfishError> {
+        let entries = self.get_log_entries(system_id, log_id)?;
+        Ok(log::entries_since(entries, since))
+    }
+
+    /// Delete every entry of a system log
+    pub fn clear_log(&self, system_id: &str, log_id: &str) -> Result<(), RedfishError> {
+        let url = format!(
+            "Systems/{}/LogServices/{}/Actions/LogService.ClearLog/",
+            system_id, log_id
+        );
+        let _: serde_json::Value = self.post(&url, &serde_json::json!({}))?;
+        Ok(())
+    }
+
+    /// Query the current state of the system's locate indicator
+    pub fn get_locate_indicator(&self) -> Result<system::LocateState, RedfishError> {
+        let s = self.get_first_system()?;
+        Ok(s.locate_state())
+    }
 
-pub struct Config {
-    pub user: Option<String>,
-    pub endpoint: String,
-    pub password: Option<String>,
-    pub port: Option<u16>,
-}
+    pub fn get_ethernet_interfaces(
+        &self,
+        system_id: &str,
+    ) -> Result<ethernet::EthernetInterfaces, RedfishError> {
+        let url = format!("Systems/{}/EthernetInterfaces/", system_id);
+        let e: ethernet::EthernetInterfaces = self.get(&url)?;
+        Ok(e)
+    }
 
-pub struct Redfish {
-    pub client: Client,
-    pub config: Config,
-}
+    /// Query a system network interface's MAC address, link state and addresses
+    pub fn get_ethernet_interface(
+        &self,
+        system_id: &str,
+        interface_id: &str,
+    ) -> Result<ethernet::EthernetInterface, RedfishError> {
+        let url = format!("Systems/{}/EthernetInterfaces/{}/", system_id, interface_id);
+        let e: ethernet::EthernetInterface = self.get(&url)?;
+        Ok(e)
+    }
 
-impl Redfish {
-    pub fn new(client: Client, config: Config) -> Self {
-        Redfish { client, config }
+    pub fn get_memory_modules(
+        &self,
+        system_id: &str,
+    ) -> Result<memory::MemoryCollection, RedfishError> {
+        let url = format!("Systems/{}/Memory/", system_id);
+        let m: memory::MemoryCollection = self.get(&url)?;
+        Ok(m)
+    }
+
+    /// Query a DIMM's capacity, type, speed and health
+    pub fn get_memory_module(
+        &self,
+        system_id: &str,
+        dimm_id: &str,
+    ) -> Result<memory::Memory, RedfishError> {
+        let url = format!("Systems/{}/Memory/{}/", system_id, dimm_id);
+        let m: memory::Memory = self.get(&url)?;
+        Ok(m)
+    }
+
+    pub fn get_processors(&self, system_id: &str) -> Result<processor::Processors, RedfishError> {
+        let url = format!("Systems/{}/Processors/", system_id);
+        let p: processor::Processors = self.get(&url)?;
+        Ok(p)
+    }
+
+    /// Query a processor's model, core and thread counts, and health
+    pub fn get_processor(
+        &self,
+        system_id: &str,
+        cpu_id: &str,
+    ) -> Result<processor::Processor, RedfishError> {
+        let url = format!("Systems/{}/Processors/{}/", system_id, cpu_id);
+        let p: processor::Processor = self.get(&url)?;
+        Ok(p)
+    }
+
+    /// Query the throttling, temperature and power metrics of one processor
+    pub fn get_processor_metrics(
+        &self,
+        system_id: &str,
+        processor_id: &str,
+    ) -> Result<processor::ProcessorMetrics, RedfishError> {
+        let url = format!(
+            "Systems/{}/Processors/{}/ProcessorMetrics/",
+            system_id, processor_id
+        );
+        let p: processor::ProcessorMetrics = self.get(&url)?;
+        Ok(p)
+    }
+
+    /// Find the processors that are currently throttling, returning their metrics.
+    /// Processors that do not publish metrics are skipped.
+    pub fn throttled_processors(
+        &self,
+        system_id: &str,
+    ) -> Result<Vec<processor::ProcessorMetrics>, RedfishError> {
+        let url = format!("Systems/{}/Processors/", system_id);
+        let processors: common::Collection = self.get(&url)?;
+        let mut throttled = Vec::new();
+        for processor in &processors.members {
+            let url = format!(
+                "{}/ProcessorMetrics/",
+                processor.path().trim_end_matches('/')
+            );
+            let metrics: Option<processor::ProcessorMetrics> = self.get_optional(&url)?;
+            throttled.extend(metrics.filter(|m| m.is_throttled()));
+        }
+        Ok(throttled)
+    }
+
+    /// Query the system's current BIOS settings
+    pub fn get_bios(&self) -> Result<bios::Bios, RedfishError> {
+        self.get_bios_for_system("1")
+    }
+
+    /// Query the current BIOS settings of a system, for hosts where it is not `1`
+    pub fn get_bios_for_system(&self, system_id: &str) -> Result<bios::Bios, RedfishError> {
+        let url = format!("Systems/{}/Bios/", system_id);
+        let b: bios::Bios = self.get(&url)?;
+        Ok(b)
+    }
+
+    /// Query every current BIOS attribute of a system by name
+    pub fn get_bios_attributes(
+        &self,
+        system_id: &str,
+    ) -> Result<HashMap<String, serde_json::Value>, RedfishError> {
+        Ok(self.get_bios_for_system(system_id)?.attributes)
+    }
+
+    /// Stage BIOS attribute changes on the system's settings resource. As with
+    /// every pending BIOS change, they only take effect once the system reboots.
+    pub fn set_bios_attributes(
+        &self,
+        system_id: &str,
+        attrs: &HashMap<String, serde_json::Value>,
+    ) -> Result<(), RedfishError> {
+        let bios = self.get_bios_for_system(system_id)?;
+        let body = serde_json::json!({ "Attributes": attrs });
+        self.patch_json(&bios.settings_path(), &body)
+    }
+
+    /// Query the raw value of a single BIOS attribute, `None` if the host does not have it
+    pub fn bios_attribute(&self, name: &str) -> Result<Option<serde_json::Value>, RedfishError> {
+        Ok(self.get_bios()?.attribute(name).cloned())
     }
 
-    pub fn get<T>(&self, api: &str) -> Result<T, reqwest::Error>
+    /// Query a single BIOS attribute as `T`, `None` if the host does not have
+    /// it. An attribute that is present but not a `T` is an `InvalidValue`.
+    pub fn bios_attribute_as<T>(&self, name: &str) -> Result<Option<T>, RedfishError>
     where
-        T: DeserializeOwned + ::std::fmt::Debug,
+        T: DeserializeOwned,
     {
-        let url = match self.config.port {
-            Some(p) => format!("https://{}:{}/{}", self.config.endpoint, p, api),
-            None => format!("https://{}/{}", self.config.endpoint, api),
-        };
-
-        let res: T = match &self.config.user {
-            Some(user) => self
-                .client
-                .get(&url)
-                .header(ACCEPT, HeaderValue::from_static("application/json"))
-                .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
-                .basic_auth(&user, self.config.password.as_ref())
-                .send()?
-                .error_for_status()?
-                .json()?,
-            None => self
-                .client
-                .get(&url)
-                .header(ACCEPT, HeaderValue::from_static("application/json"))
-                .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
-                .send()?
-                .error_for_status()?
-                .json()?,
+        self.get_bios()?.try_attribute_as(name)
+    }
+
+    fn get_bios_toggle(&self, toggle: &bios::BiosToggle) -> Result<bool, RedfishError> {
+        self.get_bios()?
+            .toggle_state(toggle)
+            .ok_or_else(|| RedfishError::NotSupported(toggle.description.to_string()))
+    }
+
+    /// Stage a vendor-neutral BIOS toggle. Only attributes the host actually
+    /// reports are written, and only with a value its attribute registry allows.
+    /// Changes take effect on the next reboot.
+    fn set_bios_toggle(
+        &self,
+        toggle: &bios::BiosToggle,
+        enabled: bool,
+    ) -> Result<(), RedfishError> {
+        let bios = self.get_bios()?;
+        let registry = self.get_bios_attribute_registry(&bios)?;
+        let body = serde_json::json!({ "Attributes": bios.toggle_attributes(toggle, enabled, &registry)? });
+        self.patch_json(&bios.settings_path(), &body)
+    }
+
+    /// The attribute registry `bios` names, describing its attributes
+    fn get_bios_attribute_registry(
+        &self,
+        bios: &bios::Bios,
+    ) -> Result<registry::AttributeRegistry, RedfishError> {
+        let registry_id = bios
+            .attribute_registry
+            .as_deref()
+            .ok_or_else(|| RedfishError::NotSupported("BIOS attribute registry".to_string()))?;
+        self.get_registry(registry_id)
+    }
+
+    /// Describe every BIOS attribute with its current value, type and allowed
+    /// values, merged from the BIOS resource and its attribute registry
+    pub fn bios_attribute_schema(&self) -> Result<Vec<bios::BiosAttributeInfo>, RedfishError> {
+        let bios = self.get_bios()?;
+        let registry = self.get_bios_attribute_registry(&bios)?;
+        Ok(bios.attribute_schema(&registry))
+    }
+
+    /// Query whether hyperthreading is enabled in the BIOS
+    pub fn get_hyperthreading(&self) -> Result<bool, RedfishError> {
+        self.get_bios_toggle(&bios::HYPERTHREADING)
+    }
+
+    /// Stage enabling or disabling hyperthreading, applied on the next reboot
+    pub fn set_hyperthreading(&self, enabled: bool) -> Result<(), RedfishError> {
+        self.set_bios_toggle(&bios::HYPERTHREADING, enabled)
+    }
+
+    /// Query whether virtualization technology is enabled in the BIOS
+    pub fn get_virtualization(&self) -> Result<bool, RedfishError> {
+        self.get_bios_toggle(&bios::VIRTUALIZATION)
+    }
+
+    /// Stage enabling or disabling virtualization technology, applied on the next reboot
+    pub fn set_virtualization(&self, enabled: bool) -> Result<(), RedfishError> {
+        self.set_bios_toggle(&bios::VIRTUALIZATION, enabled)
+    }
+
+    /// Query the composable resource blocks managed by the composition service
+    pub fn get_resource_blocks(&self) -> Result<composition::ResourceBlocks, RedfishError> {
+        let url = "CompositionService/ResourceBlocks/";
+        let r: composition::ResourceBlocks = self.get(url)?;
+        Ok(r)
+    }
+
+    /// Query a single composable resource block
+    pub fn get_resource_block(
+        &self,
+        block_id: &str,
+    ) -> Result<composition::ResourceBlock, RedfishError> {
+        let url = format!("CompositionService/ResourceBlocks/{}/", block_id);
+        let r: composition::ResourceBlock = self.get(&url)?;
+        Ok(r)
+    }
+
+    /// Check a single resource for staged changes on its `@Redfish.Settings` object
+    fn pending_change(
+        &self,
+        resource: &str,
+    ) -> Result<Option<settings::PendingChange>, RedfishError> {
+        let current: serde_json::Value = match self.get_optional(resource)? {
+            Some(c) => c,
+            None => return Ok(None),
         };
-        Ok(res)
+        let settings: common::RedfishSettings =
+            match serde_json::from_value(current["@Redfish.Settings"].clone()) {
+                Ok(s) => s,
+                Err(_) => return Ok(None),
+            };
+        let staged: serde_json::Value = match self.get_optional(settings.settings_object.path())? {
+            Some(s) => s,
+            None => return Ok(None),
+        };
+        Ok(settings::pending_change(
+            resource, &settings, &current, &staged,
+        ))
     }
 
-    pub fn get_array_controller(
+    /// List every resource with staged changes that have not been applied yet,
+    /// covering the BIOS, boot settings, secure boot and system network
+    /// interfaces, and whether each needs a reset to take effect
+    pub fn pending_changes_summary(
         &self,
-        controller_id: u64,
-    ) -> Result<storage::ArrayController, reqwest::Error> {
-        let url = format!("Systems/1/SmartStorage/ArrayControllers/{}/", controller_id);
-        let s: storage::ArrayController = self.get(&url)?;
-        Ok(s)
+        system_id: &str,
+    ) -> Result<Vec<settings::PendingChange>, RedfishError> {
+        let system = format!("Systems/{}/", system_id);
+        let mut resources = vec![
+            format!("{}Bios/", system),
+            system.clone(),
+            format!("{}SecureBoot/", system),
+        ];
+        let nics_url = format!("{}EthernetInterfaces/", system);
+        if let Some(nics) = self.get_optional::<common::Collection>(&nics_url)? {
+            resources.extend(nics.members.iter().map(|m| m.path().to_string()));
+        }
+        let mut pending = Vec::new();
+        for resource in &resources {
+            if let Some(change) = self.pending_change(resource)? {
+                pending.push(change);
+            }
+        }
+        Ok(pending)
     }
-    pub fn get_array_controllers(&self) -> Result<storage::ArrayControllers, reqwest::Error> {
-        let url = "Systems/1/SmartStorage/ArrayControllers/";
-        let s: storage::ArrayControllers = self.get(url)?;
-        Ok(s)
+
+    /// Query the network adapters installed in the chassis
+    pub fn get_network_adapters(&self) -> Result<network_adapter::NetworkAdapters, RedfishError> {
+        self.get_network_adapters_for_chassis("1")
     }
 
-    /// Query the manager status from the server
-    pub fn get_manager_status(&self) -> Result<manager::Manager, reqwest::Error> {
-        let url = "Managers/";
-        let m: manager::Manager = self.get(url)?;
-        Ok(m)
+    /// Query the network adapters installed in a chassis, for hosts where it is not `1`
+    pub fn get_network_adapters_for_chassis(
+        &self,
+        chassis_id: &str,
+    ) -> Result<network_adapter::NetworkAdapters, RedfishError> {
+        let url = format!("Chassis/{}/NetworkAdapters/", chassis_id);
+        let n: network_adapter::NetworkAdapters = self.get(&url)?;
+        Ok(n)
+    }
+
+    /// Query a single network adapter, including its controllers' firmware versions
+    pub fn get_network_adapter(
+        &self,
+        chassis_id: &str,
+        adapter_id: &str,
+    ) -> Result<network_adapter::NetworkAdapter, RedfishError> {
+        let url = format!("Chassis/{}/NetworkAdapters/{}/", chassis_id, adapter_id);
+        let n: network_adapter::NetworkAdapter = self.get(&url)?;
+        Ok(n)
+    }
+
+    /// Query the physical ports of a network adapter
+    pub fn get_network_ports(
+        &self,
+        chassis_id: &str,
+        adapter_id: &str,
+    ) -> Result<network_adapter::NetworkPorts, RedfishError> {
+        let url = format!(
+            "Chassis/{}/NetworkAdapters/{}/NetworkPorts/",
+            chassis_id, adapter_id
+        );
+        let n: network_adapter::NetworkPorts = self.get(&url)?;
+        Ok(n)
+    }
+
+    /// Query a single network adapter port, including its link state and speed
+    pub fn get_network_port(
+        &self,
+        chassis_id: &str,
+        adapter_id: &str,
+        port_id: &str,
+    ) -> Result<network_adapter::NetworkPort, RedfishError> {
+        let url = format!(
+            "Chassis/{}/NetworkAdapters/{}/NetworkPorts/{}/",
+            chassis_id, adapter_id, port_id
+        );
+        let n: network_adapter::NetworkPort = self.get(&url)?;
+        Ok(n)
+    }
+
+    /// Query the manager's configured time zone. Whether it observes daylight
+    /// saving time is available through `TimeZone::observes_dst`.
+    pub fn get_manager_timezone(
+        &self,
+        manager_id: &str,
+    ) -> Result<manager::TimeZone, RedfishError> {
+        let url = format!("Managers/{}/DateTime/", manager_id);
+        let d: manager::DateTime = self.get(&url)?;
+        Ok(d.time_zone)
+    }
+
+    /// Set the manager's time zone to one of the zones it advertises, given by
+    /// display name (e.g. `Eastern Standard Time (US)`) or POSIX rule (e.g. `EST5EDT`)
+    pub fn set_manager_timezone(&self, manager_id: &str, tz: &str) -> Result<(), RedfishError> {
+        let url = format!("Managers/{}/DateTime/", manager_id);
+        let d: manager::DateTime = self.get(&url)?;
+        let zone = d
+            .find_time_zone(tz)
+            .ok_or_else(|| RedfishError::InvalidValue {
+                property: "TimeZone".to_string(),
+                value: tz.to_string(),
+                allowed: d.time_zone_list.iter().map(|z| z.name.clone()).collect(),
+            })?;
+        let body = serde_json::json!({ "TimeZone": { "Index": zone.index } });
+        self.patch_json(&url, &body)
+    }
+
+    /// Query the iDRAC front panel LCD configuration and current message
+    pub fn get_lcd_status(&self) -> Result<manager::LcdStatus, RedfishError> {
+        let url = "Managers/iDRAC.Embedded.1/Attributes";
+        let a: manager::DellManagerAttributes = self.get(url)?;
+        Ok(a.attributes)
+    }
+
+    /// Show `msg` on the iDRAC front panel LCD
+    pub fn set_lcd_user_string(&self, msg: &str) -> Result<(), RedfishError> {
+        let url = "Managers/iDRAC.Embedded.1/Attributes";
+        let body = serde_json::json!({
+            "Attributes": {
+                "LCD.1.Configuration": "User Defined",
+                "LCD.1.UserDefinedString": msg,
+            }
+        });
+        self.patch_json(url, &body)
+    }
+
+    /// Clear the user message from the iDRAC front panel LCD and go back to
+    /// displaying the service tag
+    pub fn clear_lcd_user_string(&self) -> Result<(), RedfishError> {
+        let url = "Managers/iDRAC.Embedded.1/Attributes";
+        let body = serde_json::json!({
+            "Attributes": {
+                "LCD.1.Configuration": "Service Tag",
+                "LCD.1.UserDefinedString": "",
+            }
+        });
+        self.patch_json(url, &body)
+    }
+
+    /// List the chassis the service manages
+    pub fn get_chassis_collection(&self) -> Result<chassis::ChassisCollection, RedfishError> {
+        let url = "Chassis/";
+        let c: chassis::ChassisCollection = self.get(url)?;
+        Ok(c)
+    }
+
+    /// Query a chassis's inventory details, e.g. model and serial number
+    pub fn get_chassis(&self, chassis_id: &str) -> Result<chassis::Chassis, RedfishError> {
+        let url = format!("Chassis/{}/", chassis_id);
+        let c: chassis::Chassis = self.get(&url)?;
+        Ok(c)
+    }
+
+    /// Light or blink a chassis's identify LED to find it in a rack
+    pub fn set_indicator_led(
+        &self,
+        chassis_id: &str,
+        state: chassis::IndicatorLed,
+    ) -> Result<(), RedfishError> {
+        let url = format!("Chassis/{}/", chassis_id);
+        let body = chassis::SetIndicatorLed {
+            indicator_led: state,
+        };
+        self.patch_json(&url, &body)
+    }
+
+    /// Fetch the power, thermal and manager status at once, each on its own
+    /// thread, so a dashboard waits for the slowest request rather than all
+    /// three in turn. A part that fails, or whose thread panics, is left `None`;
+    /// only when every request returns an error is the power status error
+    /// returned.
+    pub fn get_overview(&self) -> Result<common::Overview, RedfishError> {
+        let (power, thermal, manager) = std::thread::scope(|scope| {
+            let power = scope.spawn(|| self.get_power_status());
+            let thermal = scope.spawn(|| self.get_thermal_status());
+            let manager = scope.spawn(|| self.get_manager_status());
+            (power.join().ok(), thermal.join().ok(), manager.join().ok())
+        });
+        match (power, thermal, manager) {
+            (Some(Err(e)), Some(Err(_)), Some(Err(_))) => Err(e),
+            (power, thermal, manager) => Ok(common::Overview {
+                power: power.and_then(Result::ok),
+                thermal: thermal.and_then(Result::ok),
+                manager: manager.and_then(Result::ok),
+            }),
+        }
+    }
+
+    /// Query the power status of the first chassis
+    pub fn get_power_status(&self) -> Result<power::Power, RedfishError> {
+        self.get_power_status_for_chassis("1")
     }
 
-    /// Query the power status from the server
-    pub fn get_power_status(&self) -> Result<power::Power, reqwest::Error> {
-        let url = "Chassis/1/Power/";
-        let p: power::Power = self.get(url)?;
+    /// Query the power status of a chassis, for hosts such as blade enclosures
+    /// that expose several
+    pub fn get_power_status_for_chassis(
+        &self,
+        chassis_id: &str,
+    ) -> Result<power::Power, RedfishError> {
+        let url = power::power_path(chassis_id);
+        let p: power::Power = self.get(&url)?;
         Ok(p)
     }
 
-    /// Query the thermal status from the server
-    pub fn get_thermal_status(&self) -> Result<thermal::Thermal, reqwest::Error> {
-        let url = "Chassis/1/Thermal/";
-        let t: thermal::Thermal = self.get(url)?;
+    /// Query the power cap of a chassis, from its first `PowerControl` entry
+    pub fn get_power_limit(
+        &self,
+        chassis_id: &str,
+    ) -> Result<power::PowercontrolPowerlimit, RedfishError> {
+        let url = power::power_path(chassis_id);
+        let p: power::PowerLimitControls = self.get(&url)?;
+        p.power_control
+            .into_iter()
+            .next()
+            .and_then(|c| c.power_limit)
+            .ok_or_else(|| RedfishError::NotSupported("PowerControl.PowerLimit".to_string()))
+    }
+
+    /// Cap the power a chassis may draw at `limit_watts`, or lift the cap with
+    /// `None`. The cap is checked against the consumption and capacity the
+    /// chassis reports before it is sent.
+    pub fn set_power_limit(
+        &self,
+        chassis_id: &str,
+        limit_watts: Option<u32>,
+    ) -> Result<(), RedfishError> {
+        let url = power::power_path(chassis_id);
+        let p: power::PowerLimitControls = self.get(&url)?;
+        let control = p
+            .power_control
+            .first()
+            .ok_or_else(|| RedfishError::NotSupported("PowerControl".to_string()))?;
+        if let Some(watts) = limit_watts {
+            control.check_limit(watts)?;
+        }
+        self.patch_json(&url, &power::SetPowerLimit::new(limit_watts))
+    }
+
+    /// Query every backup battery in a chassis's power subsystem
+    pub fn get_batteries(&self, chassis_id: &str) -> Result<Vec<power::Battery>, RedfishError> {
+        let url = format!("Chassis/{}/PowerSubsystem/Batteries/", chassis_id);
+        self.stream_members(&url).collect()
+    }
+
+    /// Query the thermal status of the first chassis
+    pub fn get_thermal_status(&self) -> Result<thermal::Thermal, RedfishError> {
+        self.get_thermal_status_for_chassis("1")
+    }
+
+    /// Query the thermal status of a chassis, for hosts such as blade enclosures
+    /// that expose several
+    pub fn get_thermal_status_for_chassis(
+        &self,
+        chassis_id: &str,
+    ) -> Result<thermal::Thermal, RedfishError> {
+        let url = thermal::thermal_path(chassis_id);
+        let t: thermal::Thermal = self.get(&url)?;
         Ok(t)
     }
 
+    /// Query the thermal metrics of the first chassis
+    pub fn get_thermal_metrics(&self) -> Result<Option<thermal::ThermalMetrics>, RedfishError> {
+        self.get_thermal_metrics_for_chassis("1")
+    }
+
+    /// Query a chassis's thermal metrics, including airflow, from the newer
+    /// `ThermalSubsystem` schema. Returns `None` on firmware that predates it.
+    pub fn get_thermal_metrics_for_chassis(
+        &self,
+        chassis_id: &str,
+    ) -> Result<Option<thermal::ThermalMetrics>, RedfishError> {
+        let url = format!("Chassis/{}/ThermalSubsystem/ThermalMetrics/", chassis_id);
+        self.get_optional(&url)
+    }
+
     /// Query the smart array status from the server
     pub fn get_smart_array_status(
         &self,
+        system_id: &str,
         controller_id: u64,
-    ) -> Result<storage::SmartArray, reqwest::Error> {
-        let url = format!("Systems/1/SmartStorage/ArrayControllers/{}/", controller_id);
+    ) -> Result<storage::SmartArray, RedfishError> {
+        let url = format!(
+            "Systems/{}/SmartStorage/ArrayControllers/{}/",
+            system_id, controller_id
+        );
         let s: storage::SmartArray = self.get(&url)?;
         Ok(s)
     }
+    pub fn get_smart_array_status_for_system_1(
+        &self,
+        controller_id: u64,
+    ) -> Result<storage::SmartArray, RedfishError> {
+        self.get_smart_array_status("1", controller_id)
+    }
 
     pub fn get_logical_drives(
         &self,
+        system_id: &str,
         controller_id: u64,
-    ) -> Result<storage::LogicalDrives, reqwest::Error> {
+    ) -> Result<storage::LogicalDrives, RedfishError> {
         let url = format!(
-            "Systems/1/SmartStorage/ArrayControllers/{}/LogicalDrives/",
-            controller_id
+            "Systems/{}/SmartStorage/ArrayControllers/{}/LogicalDrives/",
+            system_id, controller_id
         );
         let s: storage::LogicalDrives = self.get(&url)?;
         Ok(s)
     }
+    pub fn get_logical_drives_for_system_1(
+        &self,
+        controller_id: u64,
+    ) -> Result<storage::LogicalDrives, RedfishError> {
+        self.get_logical_drives("1", controller_id)
+    }
 
     pub fn get_physical_drive(
         &self,
+        system_id: &str,
         drive_id: u64,
         controller_id: u64,
-    ) -> Result<storage::DiskDrive, reqwest::Error> {
+    ) -> Result<storage::DiskDrive, RedfishError> {
         let url = format!(
-            "Systems/1/SmartStorage/ArrayControllers/{}/DiskDrives/{}/",
-            controller_id, drive_id,
+            "Systems/{}/SmartStorage/ArrayControllers/{}/DiskDrives/{}/",
+            system_id, controller_id, drive_id,
         );
         let d: storage::DiskDrive = self.get(&url)?;
         Ok(d)
     }
+    pub fn get_physical_drive_for_system_1(
+        &self,
+        drive_id: u64,
+        controller_id: u64,
+    ) -> Result<storage::DiskDrive, RedfishError> {
+        self.get_physical_drive("1", drive_id, controller_id)
+    }
 
     pub fn get_physical_drives(
         &self,
+        system_id: &str,
         controller_id: u64,
-    ) -> Result<storage::DiskDrives, reqwest::Error> {
+    ) -> Result<storage::DiskDrives, RedfishError> {
         let url = format!(
-            "Systems/1/SmartStorage/ArrayControllers/{}/DiskDrives/",
-            controller_id
+            "Systems/{}/SmartStorage/ArrayControllers/{}/DiskDrives/",
+            system_id, controller_id
         );
         let d: storage::DiskDrives = self.get(&url)?;
         Ok(d)
     }
+    pub fn get_physical_drives_for_system_1(
+        &self,
+        controller_id: u64,
+    ) -> Result<storage::DiskDrives, RedfishError> {
+        self.get_physical_drives("1", controller_id)
+    }
 
     pub fn get_storage_enclosures(
         &self,
+        system_id: &str,
         controller_id: u64,
-    ) -> Result<storage::StorageEnclosures, reqwest::Error> {
+    ) -> Result<storage::StorageEnclosures, RedfishError> {
         let url = format!(
-            "Systems/1/SmartStorage/ArrayControllers/{}/StorageEnclosures/",
-            controller_id
+            "Systems/{}/SmartStorage/ArrayControllers/{}/StorageEnclosures/",
+            system_id, controller_id
         );
         let s: storage::StorageEnclosures = self.get(&url)?;
         Ok(s)
     }
+    pub fn get_storage_enclosures_for_system_1(
+        &self,
+        controller_id: u64,
+    ) -> Result<storage::StorageEnclosures, RedfishError> {
+        self.get_storage_enclosures("1", controller_id)
+    }
     pub fn get_storage_enclosure(
         &self,
+        system_id: &str,
         controller_id: u64,
         enclosure_id: u64,
-    ) -> Result<storage::StorageEnclosure, reqwest::Error> {
+    ) -> Result<storage::StorageEnclosure, RedfishError> {
         let url = format!(
-            "Systems/1/SmartStorage/ArrayControllers/{}/StorageEnclosures/{}/",
-            controller_id, enclosure_id,
+            "Systems/{}/SmartStorage/ArrayControllers/{}/StorageEnclosures/{}/",
+            system_id, controller_id, enclosure_id,
         );
         let s: storage::StorageEnclosure = self.get(&url)?;
         Ok(s)
     }
+    pub fn get_storage_enclosure_for_system_1(
+        &self,
+        controller_id: u64,
+        enclosure_id: u64,
+    ) -> Result<storage::StorageEnclosure, RedfishError> {
+        self.get_storage_enclosure("1", controller_id, enclosure_id)
+    }
+
+    /// List the standard Redfish storage subsystems of a system, one per
+    /// controller on most hosts. HP iLO's equivalent is `get_array_controllers`.
+    pub fn get_storage_subsystems(
+        &self,
+        system_id: &str,
+    ) -> Result<storage_standard::StorageCollection, RedfishError> {
+        let url = format!("Systems/{}/Storage/", system_id);
+        let s: storage_standard::StorageCollection = self.get(&url)?;
+        Ok(s)
+    }
+
+    /// Query a storage subsystem, with its controllers and links to its
+    /// drives and volumes
+    pub fn get_storage(
+        &self,
+        system_id: &str,
+        storage_id: &str,
+    ) -> Result<storage_standard::Storage, RedfishError> {
+        let url = format!("Systems/{}/Storage/{}/", system_id, storage_id);
+        let s: storage_standard::Storage = self.get(&url)?;
+        Ok(s)
+    }
+
+    /// List the volumes of a storage subsystem
+    pub fn get_volumes(
+        &self,
+        system_id: &str,
+        storage_id: &str,
+    ) -> Result<storage_standard::VolumeCollection, RedfishError> {
+        let url = format!("Systems/{}/Storage/{}/Volumes/", system_id, storage_id);
+        let v: storage_standard::VolumeCollection = self.get(&url)?;
+        Ok(v)
+    }
+
+    /// Light or blink a drive's bay LED, to find it for replacement. HP iLO's
+    /// `SmartStorage` drives have no `IndicatorLED` to set.
+    pub fn set_drive_indicator(
+        &self,
+        system_id: &str,
+        storage_id: &str,
+        drive_id: &str,
+        state: chassis::IndicatorLed,
+    ) -> Result<(), RedfishError> {
+        let url = format!(
+            "Systems/{}/Storage/{}/Drives/{}/",
+            system_id, storage_id, drive_id
+        );
+        let body = chassis::SetIndicatorLed {
+            indicator_led: state,
+        };
+        self.patch_json(&url, &body)
+    }
+
+    /// Create a volume, e.g. a RAID1 mirror across two drives, and return the
+    /// `Location` the server answered with. That is the new volume when it was
+    /// created on the spot (`201 Created`), or a task monitor to poll when the
+    /// controller builds it in the background (`202 Accepted`).
+    pub fn create_volume(
+        &self,
+        system_id: &str,
+        storage_id: &str,
+        request: storage_standard::VolumeCreateRequest,
+    ) -> Result<Option<String>, RedfishError> {
+        let url = format!("Systems/{}/Storage/{}/Volumes/", system_id, storage_id);
+        self.post_action(&url, &request)
+    }
+
+    /// Fetch every drive of a storage subsystem. Drives are linked from the
+    /// subsystem rather than kept in a collection, so this takes one request
+    /// for the subsystem and one per drive.
+    pub fn get_drives(
+        &self,
+        system_id: &str,
+        storage_id: &str,
+    ) -> Result<Vec<storage_standard::Drive>, RedfishError> {
+        let storage = self.get_storage(system_id, storage_id)?;
+        storage.drives.iter().map(|d| self.get(d.path())).collect()
+    }
+
+    /// Query a standard Redfish storage volume, including any background
+    /// operations (initializing, rebuilding, expanding) it has in progress
+    pub fn get_volume(
+        &self,
+        system_id: &str,
+        storage_id: &str,
+        volume_id: &str,
+    ) -> Result<storage_standard::Volume, RedfishError> {
+        let url = format!(
+            "Systems/{}/Storage/{}/Volumes/{}/",
+            system_id, storage_id, volume_id
+        );
+        let v: storage_standard::Volume = self.get(&url)?;
+        Ok(v)
+    }
+}
+
+/// Log out of any active session, so BMCs with few session slots don't fill
+/// up with ones left behind until they time out. Call `logout` first to see
+/// whether that succeeded.
+impl Drop for Redfish {
+    fn drop(&mut self) {
+        let _ = self.logout();
+    }
+}
+
+/// The `X-Auth-Token` header carrying a session's token, kept out of debug output
+fn token_header(session: &session::SessionToken) -> HeaderValue {
+    let mut token = HeaderValue::from_str(session.token.expose())
+        .unwrap_or_else(|_| HeaderValue::from_static(""));
+    token.set_sensitive(true);
+    token
+}
+
+/// Strip what users commonly paste along with a host, e.g. `https://10.0.0.5/`,
+/// which `build_uri` would otherwise turn into `https://https://10.0.0.5//`
+fn normalize_host(host: &str) -> &str {
+    let host = host.trim();
+    let host = ["https://", "http://"]
+        .iter()
+        .find(|scheme| {
+            host.get(..scheme.len())
+                .is_some_and(|s| s.eq_ignore_ascii_case(scheme))
+        })
+        .map_or(host, |scheme| &host[scheme.len()..]);
+    host.trim_end_matches('/')
+}
+
+#[test]
+fn test_config_builder() {
+    let config = Config::builder()
+        .host("bmc.example.com")
+        .user("admin")
+        .password("hunter2")
+        .port(8443)
+        .build()
+        .unwrap();
+    assert_eq!(config.endpoint, "bmc.example.com");
+    assert_eq!(config.port, Some(8443));
+    assert_eq!(config.api_version, Some(ApiVersion::V1));
+    assert_eq!(config.password.unwrap().expose(), "hunter2");
+
+    assert!(matches!(
+        Config::builder().build(),
+        Err(RedfishError::InvalidConfig(_))
+    ));
+    assert!(matches!(
+        Config::builder().host("bmc").password("hunter2").build(),
+        Err(RedfishError::InvalidConfig(_))
+    ));
+}
+
+#[test]
+fn test_request_timeout() {
+    // Reads the request and never answers
+    let (port, _server) = testing::serve(vec![String::new()]);
+
+    let config = Config::builder()
+        .host("127.0.0.1")
+        .port(port)
+        .timeout(Duration::from_millis(200))
+        .build()
+        .unwrap();
+    let mut redfish = Redfish::with_config(config).unwrap();
+    redfish.scheme = "http";
+    let err = redfish.get::<serde_json::Value>("").unwrap_err();
+    assert!(
+        matches!(err, RedfishError::Timeout { after, .. } if after == Duration::from_millis(200)),
+        "unexpected error: {}",
+        err
+    );
+}
+
+#[test]
+fn test_normalize_host() {
+    assert_eq!(normalize_host("10.0.0.5"), "10.0.0.5");
+    assert_eq!(normalize_host("https://10.0.0.5"), "10.0.0.5");
+    assert_eq!(normalize_host("http://10.0.0.5"), "10.0.0.5");
+    assert_eq!(normalize_host("HTTPS://bmc.example.com"), "bmc.example.com");
+    assert_eq!(normalize_host("10.0.0.5/"), "10.0.0.5");
+    assert_eq!(normalize_host("https://10.0.0.5//"), "10.0.0.5");
+    assert_eq!(normalize_host(" 10.0.0.5 "), "10.0.0.5");
+    assert_eq!(normalize_host("https://"), "");
+
+    let config = Config::builder().host("https://10.0.0.5/").build().unwrap();
+    let redfish = Redfish::with_config(config).unwrap();
+    assert_eq!(
+        redfish.build_uri("Systems/1/").unwrap().as_str(),
+        "https://10.0.0.5/redfish/v1/Systems/1/"
+    );
+    let config = Config::builder().host("https://").build().unwrap();
+    assert!(matches!(
+        Redfish::with_config(config),
+        Err(RedfishError::InvalidConfig(_))
+    ));
+}
+
+#[test]
+fn test_build_uri() {
+    let config = Config::builder()
+        .host("10.0.0.5")
+        .port(8443)
+        .build()
+        .unwrap();
+    let redfish = Redfish::with_config(config).unwrap();
+    assert_eq!(
+        redfish.build_uri("").unwrap().as_str(),
+        "https://10.0.0.5:8443/redfish/v1/"
+    );
+    assert_eq!(
+        redfish
+            .build_uri("Systems/1/Storage/RAID Integrated 1/")
+            .unwrap()
+            .as_str(),
+        "https://10.0.0.5:8443/redfish/v1/Systems/1/Storage/RAID%20Integrated%201/"
+    );
+    assert_eq!(
+        redfish
+            .build_uri("Systems/1/LogServices/SEL/Entries?$skip=50")
+            .unwrap()
+            .as_str(),
+        "https://10.0.0.5:8443/redfish/v1/Systems/1/LogServices/SEL/Entries?$skip=50"
+    );
+
+    let config = Config::builder().host("bmc example").build().unwrap();
+    assert!(matches!(
+        Redfish::with_config(config),
+        Err(RedfishError::InvalidConfig(_))
+    ));
+}
+
+#[test]
+fn test_get_link() {
+    let (port, server) = testing::serve(vec!["200 OK\r\n\r\n{\"Id\": \"1\"}".to_string()]);
+
+    let config = Config::builder()
+        .host("127.0.0.1")
+        .port(port)
+        .build()
+        .unwrap();
+    let mut redfish = Redfish::with_config(config).unwrap();
+    redfish.scheme = "http";
+    let system: serde_json::Value = redfish.get_link("/redfish/v1/Systems/1/").unwrap();
+    assert_eq!(system["Id"], "1");
+    assert!(server.join().unwrap()[0].starts_with("GET /redfish/v1/Systems/1/ "));
+}
+
+#[test]
+fn test_proxy() {
+    for proxy in ["http://jump.example.com:3128", "socks5://127.0.0.1:1080"] {
+        let config = Config::builder()
+            .host("10.0.0.5")
+            .proxy(proxy)
+            .build()
+            .unwrap();
+        assert_eq!(config.proxy.as_deref(), Some(proxy));
+        assert!(Redfish::with_config(config).is_ok());
+    }
+
+    let config = Config::builder()
+        .host("10.0.0.5")
+        .proxy("http://jump example:3128")
+        .build()
+        .unwrap();
+    assert!(matches!(
+        Redfish::with_config(config),
+        Err(RedfishError::InvalidConfig(_))
+    ));
+}
+
+#[test]
+fn test_accept_override() {
+    let accept = |config: Config| {
+        let redfish = Redfish::with_config(config).unwrap();
+        let req = redfish.request(Method::GET, "").unwrap().build().unwrap();
+        req.headers()[ACCEPT].to_str().unwrap().to_string()
+    };
+    let config = Config::builder().host("10.0.0.5").build().unwrap();
+    assert_eq!(accept(config), "application/json");
+    let config = Config::builder()
+        .host("10.0.0.5")
+        .accept("application/json;odata.metadata=minimal")
+        .build()
+        .unwrap();
+    assert_eq!(accept(config), "application/json;odata.metadata=minimal");
+}
+
+#[test]
+fn test_default_headers() {
+    let mut defaults = HeaderMap::new();
+    defaults.insert("OData-Version", HeaderValue::from_static("4.0"));
+    defaults.insert(
+        ACCEPT,
+        HeaderValue::from_static("application/json;charset=utf-8"),
+    );
+    let config = Config::builder()
+        .host("10.0.0.5")
+        .default_headers(defaults)
+        .build()
+        .unwrap();
+    let redfish = Redfish::with_config(config).unwrap();
+    let req = redfish.request(Method::GET, "").unwrap().build().unwrap();
+    assert_eq!(req.headers()["OData-Version"], "4.0");
+    assert_eq!(req.headers()[ACCEPT], "application/json;charset=utf-8");
+    assert_eq!(req.headers().get_all(ACCEPT).iter().count(), 1);
+    assert_eq!(req.headers()[CONTENT_TYPE], "application/json");
+}
+
+#[test]
+fn test_client_cert() {
+    let config = Config::builder()
+        .host("10.0.0.5")
+        .client_cert_pem("not a certificate", "not a key")
+        .build()
+        .unwrap();
+    assert!(matches!(
+        Redfish::with_config(config),
+        Err(RedfishError::InvalidConfig(_))
+    ));
+
+    let config = Config {
+        endpoint: "10.0.0.5".to_string(),
+        client_cert_pem: Some(b"-----BEGIN CERTIFICATE-----".to_vec()),
+        ..Config::default()
+    };
+    assert!(matches!(
+        Redfish::with_config(config),
+        Err(RedfishError::InvalidConfig(_))
+    ));
+
+    let config = Config {
+        endpoint: "10.0.0.5".to_string(),
+        client_cert_pem: Some(Vec::new()),
+        client_key_pem: Some(Vec::new()),
+        pinned_cert_sha256: Some("ab".repeat(32)),
+        ..Config::default()
+    };
+    assert!(matches!(
+        Redfish::with_config(config),
+        Err(RedfishError::InvalidConfig(_))
+    ));
+}
+
+#[test]
+fn test_auth_method() {
+    let authorization = |auth: AuthMethod| {
+        let config = Config::builder()
+            .host("10.0.0.5")
+            .user("admin")
+            .password("hunter2")
+            .auth(auth)
+            .build()
+            .unwrap();
+        let redfish = Redfish::with_config(config).unwrap();
+        let req = redfish.request(Method::GET, "").unwrap().build().unwrap();
+        req.headers()
+            .get(reqwest::header::AUTHORIZATION)
+            .map(|h| h.to_str().unwrap().to_string())
+    };
+    assert_eq!(
+        authorization(AuthMethod::Basic).as_deref(),
+        Some("Basic YWRtaW46aHVudGVyMg==")
+    );
+    assert_eq!(
+        authorization(AuthMethod::Bearer(Secret::new("eyJhbGciOi"))).as_deref(),
+        Some("Bearer eyJhbGciOi")
+    );
+    assert_eq!(authorization(AuthMethod::Session), None);
+    assert_eq!(authorization(AuthMethod::None), None);
+}
+
+#[test]
+fn test_get_raw() {
+    let (port, _server) = testing::serve(vec![
+        "404 Not Found\r\nETag: W/\"42\"\r\n\r\n{\"error\": \"not found\"}".to_string(),
+    ]);
+
+    let config = Config::builder()
+        .host("127.0.0.1")
+        .port(port)
+        .build()
+        .unwrap();
+    let mut redfish = Redfish::with_config(config).unwrap();
+    redfish.scheme = "http";
+    let raw = redfish.get_raw("Systems/2/").unwrap();
+    assert_eq!(raw.status, 404);
+    assert_eq!(raw.etag(), Some("W/\"42\""));
+    assert_eq!(raw.body, r#"{"error": "not found"}"#);
+}
+
+#[test]
+fn test_logout_on_drop() {
+    let (port, server) = testing::serve(vec![
+        "201 Created\r\nX-Auth-Token: 0f5e\r\nLocation: /redfish/v1/SessionService/Sessions/7/"
+            .to_string(),
+        "204 No Content".to_string(),
+    ]);
+
+    let config = Config::builder()
+        .host("127.0.0.1")
+        .port(port)
+        .user("admin")
+        .password("hunter2")
+        .build()
+        .unwrap();
+    let mut redfish = Redfish::with_config(config).unwrap();
+    redfish.scheme = "http";
+    redfish.login().unwrap();
+    drop(redfish);
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("POST /redfish/v1/SessionService/Sessions/"));
+    assert!(requests[1].starts_with("DELETE /redfish/v1/SessionService/Sessions/7/"));
+    assert!(
+        requests[1]
+            .to_ascii_lowercase()
+            .contains("x-auth-token: 0f5e")
+    );
+}
+
+#[test]
+fn test_get_overview_partial() {
+    // Power, Thermal, and Managers/ followed by the first manager
+    let (port, _server) = testing::serve_with(4, |request| {
+        let request_line = request.lines().next().unwrap_or_default();
+        if request_line.contains("/Power/") {
+            format!("200 OK\r\n\r\n{}", include_str!("../tests/power.json"))
+        } else if request_line.contains("/Thermal/") {
+            "404 Not Found".to_string()
+        } else if request_line.contains("/Managers/iDRAC.Embedded.1") {
+            format!("200 OK\r\n\r\n{}", include_str!("../tests/manager.json"))
+        } else {
+            format!("200 OK\r\n\r\n{}", include_str!("../tests/managers.json"))
+        }
+    });
+
+    let config = Config::builder()
+        .host("127.0.0.1")
+        .port(port)
+        .build()
+        .unwrap();
+    let mut redfish = Redfish::with_config(config).unwrap();
+    redfish.scheme = "http";
+    let overview = redfish.get_overview().unwrap();
+    assert!(overview.power.is_some());
+    assert!(overview.thermal.is_none());
+    assert!(overview.manager.is_some());
+}
+
+#[test]
+fn test_session_relogin() {
+    let created = |token: &str| {
+        format!(
+            "201 Created\r\nX-Auth-Token: {}\r\nLocation: /redfish/v1/SessionService/Sessions/{}/",
+            token, token
+        )
+    };
+    let unauthorized = "401 Unauthorized".to_string();
+    let (port, server) = testing::serve(vec![
+        created("a1"),
+        unauthorized.clone(),
+        created("b2"),
+        "200 OK\r\n\r\n{}".to_string(),
+        unauthorized.clone(),
+        created("c3"),
+        unauthorized,
+    ]);
+
+    let config = Config::builder()
+        .host("127.0.0.1")
+        .port(port)
+        .user("admin")
+        .password("hunter2")
+        .auth(AuthMethod::Session)
+        .build()
+        .unwrap();
+    let mut redfish = Redfish::with_config(config).unwrap();
+    redfish.scheme = "http";
+    redfish.login().unwrap();
+    let _: serde_json::Value = redfish.get("Systems/1/").unwrap();
+    let err = redfish.get::<serde_json::Value>("Systems/1/").unwrap_err();
+    assert!(
+        matches!(err, RedfishError::Unauthorized { .. }),
+        "unexpected error: {}",
+        err
+    );
+    // The session was dropped along with the rejected token, so nothing to delete
+    assert!(redfish.session.lock().unwrap().is_none());
+    drop(redfish);
+
+    let requests: Vec<String> = server
+        .join()
+        .unwrap()
+        .iter()
+        .map(|r| r.to_ascii_lowercase())
+        .collect();
+    assert!(requests[1].contains("x-auth-token: a1"));
+    assert!(requests[2].starts_with("post /redfish/v1/sessionservice/sessions/"));
+    assert!(requests[3].contains("x-auth-token: b2"));
+    assert!(requests[4].contains("x-auth-token: b2"));
+    assert!(requests[6].contains("x-auth-token: c3"));
+}
+
+#[test]
+fn test_patch_precondition_required() {
+    let (port, server) = testing::serve(vec![
+        "428 Precondition Required".to_string(),
+        "200 OK\r\nETag: W/\"7\"\r\n\r\n{}".to_string(),
+        "204 No Content".to_string(),
+    ]);
+
+    let config = Config::builder()
+        .host("127.0.0.1")
+        .port(port)
+        .build()
+        .unwrap();
+    let mut redfish = Redfish::with_config(config).unwrap();
+    redfish.scheme = "http";
+    redfish
+        .patch_json("Systems/1/", &serde_json::json!({ "AssetTag": "R12-21" }))
+        .unwrap();
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("PATCH /redfish/v1/Systems/1/"));
+    assert!(!requests[0].to_ascii_lowercase().contains("if-match"));
+    assert!(requests[1].starts_with("GET /redfish/v1/Systems/1/"));
+    assert!(requests[2].starts_with("PATCH /redfish/v1/Systems/1/"));
+    assert!(
+        requests[2]
+            .to_ascii_lowercase()
+            .contains("if-match: w/\"7\"")
+    );
+}
+
+#[test]
+fn test_from_shared_client() {
+    let client = Client::new();
+    let hosts = ["10.0.0.5", "10.0.0.6"].map(|host| {
+        let config = Config::builder().host(host).build().unwrap();
+        Redfish::from_shared_client(&client, config)
+    });
+    assert_eq!(hosts[1].config.endpoint, "10.0.0.6");
+    assert_eq!(
+        hosts[0].build_uri("Chassis/").unwrap().as_str(),
+        "https://10.0.0.5/redfish/v1/Chassis/"
+    );
+}
+
+#[test]
+fn test_api_version_base_path() {
+    let config = Config::builder()
+        .host("10.0.0.5")
+        .api_version(ApiVersion::V1)
+        .build()
+        .unwrap();
+    let redfish = Redfish::with_config(config).unwrap();
+    assert_eq!(
+        redfish.build_uri("Chassis/1/Power/").unwrap().as_str(),
+        "https://10.0.0.5/redfish/v1/Chassis/1/Power/"
+    );
+
+    assert_eq!("v1".parse::<ApiVersion>().unwrap(), ApiVersion::V1);
+    for version in ["v2", "1", ""] {
+        assert!(matches!(
+            version.parse::<ApiVersion>(),
+            Err(RedfishError::InvalidConfig(_))
+        ));
+    }
+
+    let config = Config {
+        endpoint: "10.0.0.5/redfish/v1".to_string(),
+        api_version: None,
+        ..Config::default()
+    };
+    let redfish = Redfish::with_config(config).unwrap();
+    assert_eq!(
+        redfish.build_uri("Chassis/1/Power/").unwrap().as_str(),
+        "https://10.0.0.5/redfish/v1/Chassis/1/Power/"
+    );
+}
+
+#[test]
+fn test_config_from_env() {
+    let env = |vars: &'static [(&'static str, &'static str)]| {
+        move |name: &str| {
+            vars.iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, v)| v.to_string())
+        }
+    };
+    let config = Config::from_lookup(
+        "10.0.0.5",
+        env(&[("REDFISH_USER", "admin"), ("REDFISH_PASSWORD", "hunter2")]),
+    )
+    .unwrap();
+    assert_eq!(config.user.as_deref(), Some("admin"));
+    assert_eq!(config.password.unwrap().expose(), "hunter2");
+    assert_eq!(config.port, None);
+
+    let config = Config::from_lookup(
+        "10.0.0.5",
+        env(&[
+            ("REDFISH_USER", "admin"),
+            ("REDFISH_PASSWORD", "hunter2"),
+            ("REDFISH_PORT", "8443"),
+        ]),
+    )
+    .unwrap();
+    assert_eq!(config.port, Some(8443));
+
+    let missing = Config::from_lookup("10.0.0.5", env(&[("REDFISH_USER", "admin")]));
+    assert!(
+        matches!(&missing, Err(RedfishError::InvalidConfig(why)) if why.contains("REDFISH_PASSWORD"))
+    );
+    let bad_port = Config::from_lookup(
+        "10.0.0.5",
+        env(&[
+            ("REDFISH_USER", "admin"),
+            ("REDFISH_PASSWORD", "hunter2"),
+            ("REDFISH_PORT", "https"),
+        ]),
+    );
+    assert!(matches!(bad_port, Err(RedfishError::InvalidConfig(_))));
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn test_config_from_toml() {
+    let config = Config::from_toml_str(
+        r#"
+        host = "10.0.0.5"
+        user = "admin"
+        password = "hunter2"
+        port = 8443
+        accept_invalid_certs = true
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.endpoint, "10.0.0.5");
+    assert_eq!(config.port, Some(8443));
+    assert!(config.accept_invalid_certs);
+
+    assert!(matches!(
+        Config::from_toml_str(r#"user = "admin""#),
+        Err(RedfishError::InvalidConfig(_))
+    ));
+    assert!(matches!(
+        Config::from_toml_str(
+            r#"host = "10.0.0.5"
+password = "hunter2""#
+        ),
+        Err(RedfishError::InvalidConfig(_))
+    ));
 }
@@ -0,0 +1,106 @@
+use crate::common::*;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct LogService {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub entries: Option<ODataId>,
+    pub id: String,
+    pub max_number_of_records: Option<u64>,
+    pub name: String,
+    /// e.g. `WrapsWhenFull` or `NeverOverWrites`
+    #[serde(rename = "OverWritePolicy")]
+    pub overwrite_policy: Option<String>,
+    pub service_enabled: Option<bool>,
+}
+
+#[test]
+fn test_log_service_parser() {
+    let test_data = include_str!("../tests/log-service.json");
+    let result: LogService = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.overwrite_policy.as_deref(), Some("WrapsWhenFull"));
+    println!("result: {:#?}", result);
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct LogEntry {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub created: Option<String>,
+    /// e.g. `SEL`, `Event` or `Oem`
+    pub entry_type: Option<String>,
+    pub id: String,
+    pub message: Option<String>,
+    pub message_id: Option<String>,
+    pub name: String,
+    /// `OK`, `Warning` or `Critical`
+    pub severity: Option<String>,
+}
+
+impl LogEntry {
+    /// When the entry was logged, `None` if it has no parseable `Created` time
+    #[cfg(feature = "time")]
+    pub fn created_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.created.as_deref().and_then(parse_timestamp)
+    }
+}
+
+/// The entries logged after `since`. Entries without a `Created` time can't
+/// be placed and are left out.
+#[cfg(feature = "time")]
+pub fn entries_since(
+    entries: Vec<LogEntry>,
+    since: chrono::DateTime<chrono::Utc>,
+) -> Vec<LogEntry> {
+    entries
+        .into_iter()
+        .filter(|e| e.created_at().is_some_and(|created| created > since))
+        .collect()
+}
+
+/// One page of a log's entries. Unlike most collections the members are
+/// embedded whole rather than as links.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct LogEntryCollection {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    #[serde(default)]
+    pub members: Vec<LogEntry>,
+    #[serde(rename = "Members@odata.count")]
+    pub members_odata_count: Option<i64>,
+    #[serde(rename = "Members@odata.nextLink")]
+    pub next_link: Option<String>,
+    pub name: String,
+}
+
+#[test]
+fn test_log_entries_parser() {
+    let test_data = include_str!("../tests/log-entries.json");
+    let result: LogEntryCollection = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.members.len(), 2);
+    assert_eq!(
+        result.next_link.as_deref(),
+        Some("/redfish/v1/Systems/1/LogServices/SEL/Entries?$skip=2")
+    );
+    println!("result: {:#?}", result);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_entries_since() {
+    let test_data = include_str!("../tests/log-entries.json");
+    let result: LogEntryCollection = serde_json::from_str(&test_data).unwrap();
+    let since = parse_timestamp("2026-09-28T14:03:00+00:00").unwrap();
+    let recent = entries_since(result.members.clone(), since);
+    assert_eq!(recent.len(), 1);
+    assert_eq!(
+        recent[0].created.as_deref(),
+        Some("2026-09-28T14:05:37+00:00")
+    );
+
+    let since = parse_timestamp("2026-09-28T16:05:37+02:00").unwrap();
+    assert!(entries_since(result.members, since).is_empty());
+}
@@ -1,24 +1,25 @@
 use crate::common::*;
+use crate::error::RedfishError;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ActionsManagerReset {
     pub target: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Action {
     #[serde(rename = "#Manager.Reset")]
     pub manager_reset: ActionsManagerReset,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Availableaction {
     pub action: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Commandshell {
     pub connect_types_supported: Vec<String>,
@@ -27,14 +28,14 @@ pub struct Commandshell {
     pub service_enabled: bool,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct OemHpActionshpiloResetToFactoryDefault {
     #[serde(rename = "ResetType@Redfish.AllowableValues")]
     pub reset_type_redfish_allowable_values: Vec<String>,
     pub target: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct OemHpAction {
     #[serde(rename = "#HpiLO.ClearRestApiState")]
     pub hpi_lo_clear_rest_api_state: ActionsManagerReset,
@@ -44,21 +45,21 @@ pub struct OemHpAction {
     pub hpi_lo_i_lo_functionality: ActionsManagerReset,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct OemHpAvailableactionsCapability {
     pub allowable_values: Vec<String>,
     pub property_name: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct OemHpAvailableaction {
     pub action: String,
     pub capabilities: Vec<OemHpAvailableactionsCapability>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct OemHpFederationconfig {
     #[serde(rename = "IPv6MulticastScope")]
@@ -70,7 +71,7 @@ pub struct OemHpFederationconfig {
     pub i_lo_federation_management: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct OemHpFirmwareCurrent {
     pub date: String,
@@ -81,13 +82,13 @@ pub struct OemHpFirmwareCurrent {
     pub version_string: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct OemHpFirmware {
     pub current: OemHpFirmwareCurrent,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct OemHpLicense {
     pub license_key: String,
@@ -95,7 +96,7 @@ pub struct OemHpLicense {
     pub license_type: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct OemHpIloselftestresult {
     pub notes: String,
@@ -112,7 +113,7 @@ impl crate::common::Status for OemHpIloselftestresult {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct OemHp {
     #[serde(flatten)]
@@ -137,19 +138,19 @@ pub struct OemHp {
     pub links: LinkType,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Oem {
     pub hp: OemHp,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Status {
     pub state: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Manager {
     #[serde(flatten)]
@@ -187,9 +188,388 @@ impl StatusVec for Manager {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FederationGroupPrivileges {
+    #[serde(rename = "HostBIOSConfigPriv")]
+    pub host_bios_config_priv: bool,
+    #[serde(rename = "HostNICConfigPriv")]
+    pub host_nic_config_priv: bool,
+    #[serde(rename = "HostStorageConfigPriv")]
+    pub host_storage_config_priv: bool,
+    #[serde(rename = "LoginPriv")]
+    pub login_priv: bool,
+    #[serde(rename = "RemoteConsolePriv")]
+    pub remote_console_priv: bool,
+    #[serde(rename = "UserConfigPriv")]
+    pub user_config_priv: bool,
+    #[serde(rename = "VirtualMediaPriv")]
+    pub virtual_media_priv: bool,
+    #[serde(rename = "VirtualPowerAndResetPriv")]
+    pub virtual_power_and_reset_priv: bool,
+    #[serde(rename = "iLOConfigPriv")]
+    pub i_lo_config_priv: bool,
+}
+
+/// A single iLO Federation group this manager is a member of
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct FederationGroup {
+    #[serde(flatten)]
+    pub odata: ODataLinks,
+    pub id: Option<String>,
+    pub name: String,
+    pub privileges: FederationGroupPrivileges,
+    #[serde(rename = "Type")]
+    pub group_type: String,
+}
+
+/// The `Managers` collection, usually holding the one BMC of the host
+pub type ManagerCollection = Collection;
+
+/// The iLO Federation groups this manager is a member of
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct FederationGroups {
+    #[serde(flatten)]
+    pub mult_hardware: crate::storage::MultHardware,
+    #[serde(rename = "Type")]
+    pub group_type: String,
+}
+
+/// The kind of diagnostic data to collect into the manager's dump log service
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticDataType {
+    Manager,
+    PreOS,
+    OS,
+    OEM,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CollectDiagnosticData {
+    pub diagnostic_data_type: DiagnosticDataType,
+}
+
+/// How a manager is restarted through `Manager.Reset`
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum ManagerResetType {
+    GracefulRestart,
+    ForceRestart,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ManagerReset {
+    pub reset_type: ManagerResetType,
+}
+
+/// How much of the manager's configuration survives `Manager.ResetToDefaults`
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum ResetToDefaultsType {
+    ResetAll,
+    PreserveNetworkAndUsers,
+    PreserveNetwork,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResetToDefaults {
+    pub reset_type: ResetToDefaultsType,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct TimeZone {
+    pub index: i64,
+    pub name: String,
+    pub utc_offset: String,
+    /// The POSIX TZ rule for the zone, e.g. `EST5EDT`
+    pub value: String,
+}
+
+impl TimeZone {
+    /// Whether the zone's POSIX TZ rule has a daylight saving component, which
+    /// is how iLO encodes DST
+    pub fn observes_dst(&self) -> bool {
+        let rule = self.value.trim();
+        // Standard time name, either alphabetic or quoted in <>
+        let rest = match rule.strip_prefix('<') {
+            Some(quoted) => quoted.split_once('>').map(|(_, r)| r).unwrap_or(""),
+            None => rule.trim_start_matches(|c: char| c.is_ascii_alphabetic()),
+        };
+        // UTC offset
+        let rest = rest.trim_start_matches(|c: char| c.is_ascii_digit() || "+-:".contains(c));
+        !rest.is_empty()
+    }
+}
+
+/// The iLO date and time service, holding the manager's configured time zone
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct DateTime {
+    #[serde(flatten)]
+    pub odata: ODataLinks,
+    pub date_time: String,
+    #[serde(rename = "NTPServers", default)]
+    pub ntp_servers: Vec<String>,
+    pub propagate_time_to_host: Option<bool>,
+    pub time_zone: TimeZone,
+    #[serde(default)]
+    pub time_zone_list: Vec<TimeZone>,
+}
+
+impl DateTime {
+    /// Look a zone up in the allowable list by its display name or POSIX rule
+    pub fn find_time_zone(&self, tz: &str) -> Option<&TimeZone> {
+        self.time_zone_list
+            .iter()
+            .find(|z| z.name == tz || z.value == tz)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Protocol {
+    pub port: Option<i64>,
+    pub protocol_enabled: Option<bool>,
+}
+
+/// The network services the manager exposes and whether each is enabled
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct NetworkProtocol {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    #[serde(rename = "FQDN")]
+    pub fqdn: Option<String>,
+    pub host_name: Option<String>,
+    #[serde(rename = "HTTP")]
+    pub http: Option<Protocol>,
+    #[serde(rename = "HTTPS")]
+    pub https: Option<Protocol>,
+    pub id: String,
+    #[serde(rename = "IPMI")]
+    pub ipmi: Option<Protocol>,
+    #[serde(rename = "KVMIP")]
+    pub kvmip: Option<Protocol>,
+    pub name: String,
+    #[serde(rename = "SNMP")]
+    pub snmp: Option<Protocol>,
+    #[serde(rename = "SSDP")]
+    pub ssdp: Option<Protocol>,
+    #[serde(rename = "SSH")]
+    pub ssh: Option<Protocol>,
+    pub virtual_media: Option<Protocol>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum FlowControl {
+    None,
+    Software,
+    Hardware,
+}
+
+/// A serial port of the manager, used for serial-over-LAN console redirection
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SerialInterface {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    /// The baud rate as a string, e.g. `115200`
+    pub bit_rate: Option<String>,
+    #[serde(rename = "BitRate@Redfish.AllowableValues", default)]
+    pub bit_rate_allowable_values: Vec<String>,
+    pub flow_control: Option<FlowControl>,
+    #[serde(rename = "FlowControl@Redfish.AllowableValues", default)]
+    pub flow_control_allowable_values: Vec<FlowControl>,
+    pub id: String,
+    pub interface_enabled: Option<bool>,
+    pub name: String,
+}
+
+/// Serial console redirection settings, from either the manager's serial
+/// interface or the vendor BIOS attributes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerialConsoleConfig {
+    pub enabled: Option<bool>,
+    pub bit_rate: Option<u32>,
+    pub flow_control: Option<FlowControl>,
+}
+
+impl SerialInterface {
+    pub fn console_config(&self) -> SerialConsoleConfig {
+        SerialConsoleConfig {
+            enabled: self.interface_enabled,
+            bit_rate: self.bit_rate.as_deref().and_then(|b| b.parse().ok()),
+            flow_control: self.flow_control,
+        }
+    }
+
+    /// Check a new setting against the values the interface advertises. An
+    /// interface that advertises nothing accepts anything.
+    pub fn validate(&self, bit_rate: u32, flow_control: FlowControl) -> Result<(), RedfishError> {
+        let rate = bit_rate.to_string();
+        if !self.bit_rate_allowable_values.is_empty()
+            && !self.bit_rate_allowable_values.contains(&rate)
+        {
+            return Err(RedfishError::InvalidValue {
+                property: "BitRate".to_string(),
+                value: rate,
+                allowed: self.bit_rate_allowable_values.clone(),
+            });
+        }
+        if !self.flow_control_allowable_values.is_empty()
+            && !self.flow_control_allowable_values.contains(&flow_control)
+        {
+            return Err(RedfishError::InvalidValue {
+                property: "FlowControl".to_string(),
+                value: format!("{:?}", flow_control),
+                allowed: self
+                    .flow_control_allowable_values
+                    .iter()
+                    .map(|f| format!("{:?}", f))
+                    .collect(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// The iDRAC front panel LCD configuration
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LcdStatus {
+    #[serde(rename = "LCD.1.Configuration")]
+    pub configuration: Option<String>,
+    #[serde(rename = "LCD.1.CurrentDisplay")]
+    pub current_display: Option<String>,
+    #[serde(rename = "LCD.1.UserDefinedString")]
+    pub user_defined_string: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct DellManagerAttributes {
+    pub attributes: LcdStatus,
+}
+
 #[test]
 fn test_manager_parser() {
     let test_data = include_str!("../tests/manager.json");
     let result: Manager = serde_json::from_str(&test_data).unwrap();
     println!("result: {:#?}", result);
 }
+
+#[test]
+fn test_federation_groups_parser() {
+    let test_data = include_str!("../tests/federation-groups.json");
+    let result: FederationGroups = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_federation_group_parser() {
+    let test_data = include_str!("../tests/federation-group.json");
+    let result: FederationGroup = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_dell_lcd_parser() {
+    let test_data = include_str!("../tests/dell-manager-attributes.json");
+    let result: DellManagerAttributes = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_manager_datetime_parser() {
+    let test_data = include_str!("../tests/manager-datetime.json");
+    let result: DateTime = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+    assert!(result.time_zone.observes_dst());
+    assert_eq!(result.find_time_zone("MST7").map(|z| z.index), Some(26));
+    assert!(result.find_time_zone("Mars Standard Time").is_none());
+}
+
+#[test]
+fn test_time_zone_dst() {
+    let zone = |value: &str| TimeZone {
+        index: 0,
+        name: String::new(),
+        utc_offset: String::new(),
+        value: value.to_string(),
+    };
+    assert!(zone("EST5EDT").observes_dst());
+    assert!(zone("CET-1CEST,M3.5.0,M10.5.0/3").observes_dst());
+    assert!(!zone("GMT0").observes_dst());
+    assert!(!zone("MST7").observes_dst());
+    assert!(!zone("<+0530>-5:30").observes_dst());
+}
+
+#[test]
+fn test_network_protocol_parser() {
+    let test_data = include_str!("../tests/network-protocol.json");
+    let result: NetworkProtocol = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+    assert_eq!(result.ipmi.and_then(|p| p.protocol_enabled), Some(false));
+}
+
+#[test]
+fn test_manager_reset_body() {
+    let body = ManagerReset {
+        reset_type: ManagerResetType::GracefulRestart,
+    };
+    assert_eq!(
+        serde_json::to_value(&body).unwrap(),
+        serde_json::json!({ "ResetType": "GracefulRestart" })
+    );
+}
+
+#[test]
+fn test_reset_to_defaults_body() {
+    let body = ResetToDefaults {
+        reset_type: ResetToDefaultsType::PreserveNetworkAndUsers,
+    };
+    assert_eq!(
+        serde_json::to_value(&body).unwrap(),
+        serde_json::json!({ "ResetType": "PreserveNetworkAndUsers" })
+    );
+}
+
+#[test]
+fn test_serial_interface_parser() {
+    let test_data = include_str!("../tests/serial-interface.json");
+    let result: SerialInterface = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(
+        result.console_config(),
+        SerialConsoleConfig {
+            enabled: Some(true),
+            bit_rate: Some(115200),
+            flow_control: Some(FlowControl::None),
+        }
+    );
+    assert!(result.validate(57600, FlowControl::Hardware).is_ok());
+    assert!(matches!(
+        result.validate(14400, FlowControl::None),
+        Err(RedfishError::InvalidValue { .. })
+    ));
+    assert!(matches!(
+        result.validate(115200, FlowControl::Software),
+        Err(RedfishError::InvalidValue { .. })
+    ));
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_manager_roundtrip() {
+    assert_roundtrip::<Manager>(include_str!("../tests/manager.json"));
+    assert_roundtrip::<NetworkProtocol>(include_str!("../tests/network-protocol.json"));
+}
+
+#[test]
+fn test_manager_collection_parser() {
+    let test_data = include_str!("../tests/managers.json");
+    let result: ManagerCollection = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.members[0].path(), "Managers/iDRAC.Embedded.1");
+    println!("result: {:#?}", result);
+}
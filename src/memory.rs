@@ -0,0 +1,34 @@
+use crate::common::*;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Memory {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    #[serde(rename = "CapacityMiB")]
+    pub capacity_mib: Option<i64>,
+    pub device_locator: Option<String>,
+    pub id: String,
+    /// e.g. `DDR4`, `DDR5`
+    pub memory_device_type: Option<String>,
+    pub name: String,
+    pub operating_speed_mhz: Option<i64>,
+    pub rank_count: Option<i64>,
+    pub status: Option<SomeStatus>,
+}
+
+#[test]
+fn test_memory_parser() {
+    let test_data = include_str!("../tests/memory.json");
+    let result: Memory = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
+
+pub type MemoryCollection = Collection;
+
+#[test]
+fn test_memory_collection_parser() {
+    let test_data = include_str!("../tests/memory-collection.json");
+    let result: MemoryCollection = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
@@ -0,0 +1,129 @@
+use crate::common::*;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct DataCenterBridging {
+    pub capable: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Sriov {
+    #[serde(rename = "SRIOVVEPACapable")]
+    pub sriov_vepa_capable: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct VirtualFunction {
+    pub device_max_count: Option<i64>,
+    pub min_assignment_group_size: Option<i64>,
+    pub network_port_max_count: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct VirtualizationOffload {
+    #[serde(rename = "SRIOV")]
+    pub sriov: Option<Sriov>,
+    pub virtual_function: Option<VirtualFunction>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ControllerCapabilities {
+    pub data_center_bridging: Option<DataCenterBridging>,
+    pub network_device_function_count: Option<i64>,
+    pub network_port_count: Option<i64>,
+    pub virtualization_offload: Option<VirtualizationOffload>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Controller {
+    pub controller_capabilities: Option<ControllerCapabilities>,
+    pub firmware_package_version: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct NetworkAdapter {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    #[serde(default)]
+    pub controllers: Vec<Controller>,
+    pub id: String,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub name: String,
+    pub part_number: Option<String>,
+    pub serial_number: Option<String>,
+    pub status: Option<SomeStatus>,
+}
+
+impl NetworkAdapter {
+    /// The firmware package version reported by each controller on the adapter
+    pub fn firmware_versions(&self) -> Vec<&str> {
+        self.controllers
+            .iter()
+            .filter_map(|c| c.firmware_package_version.as_deref())
+            .collect()
+    }
+}
+
+#[test]
+fn test_network_adapter_parser() {
+    let test_data = include_str!("../tests/network-adapter.json");
+    let result: NetworkAdapter = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+    assert_eq!(result.firmware_versions(), vec!["20.14.62"]);
+}
+
+pub type NetworkAdapters = Collection;
+
+#[test]
+fn test_network_adapters_parser() {
+    let test_data = include_str!("../tests/network-adapters.json");
+    let result: NetworkAdapters = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
+
+/// A physical port on a network adapter
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct NetworkPort {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    #[serde(default)]
+    pub associated_network_addresses: Vec<String>,
+    pub current_link_speed_mbps: Option<i64>,
+    pub id: String,
+    pub link_status: Option<String>,
+    pub name: String,
+    pub physical_port_number: Option<String>,
+    pub status: Option<SomeStatus>,
+}
+
+impl NetworkPort {
+    /// Whether the port reports an established link
+    pub fn is_link_up(&self) -> bool {
+        self.link_status.as_deref() == Some("Up")
+    }
+}
+
+#[test]
+fn test_network_port_parser() {
+    let test_data = include_str!("../tests/network-port.json");
+    let result: NetworkPort = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+    assert!(result.is_link_up());
+    assert_eq!(result.current_link_speed_mbps, Some(25000));
+}
+
+pub type NetworkPorts = Collection;
+
+#[test]
+fn test_network_ports_parser() {
+    let test_data = include_str!("../tests/network-ports.json");
+    let result: NetworkPorts = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
@@ -0,0 +1,144 @@
+use crate::common::*;
+use crate::{Redfish, RedfishError};
+use serde::de::DeserializeOwned;
+use std::collections::{HashSet, VecDeque};
+use std::marker::PhantomData;
+
+/// A lazy iterator over the members of a collection, returned by
+/// `Redfish::stream_members`. Pages are fetched only once the members of the
+/// previous page have been consumed, and each member is resolved as it is
+/// yielded, so memory stays bounded however large the collection is.
+///
+/// Like `Redfish::get_all`, a walk that repeats a page or runs past `MAX_PAGES`
+/// fails with `RedfishError::Pagination`. Iteration stops after the first
+/// error is yielded.
+pub struct Members<'a, T> {
+    redfish: &'a Redfish,
+    pending: VecDeque<String>,
+    next_page: Option<String>,
+    guard: PageGuard,
+    _member: PhantomData<T>,
+}
+
+impl<'a, T> Members<'a, T> {
+    pub(crate) fn new(redfish: &'a Redfish, collection_path: &str) -> Self {
+        Members {
+            redfish,
+            pending: VecDeque::new(),
+            next_page: Some(collection_path.to_string()),
+            guard: PageGuard::new(collection_path),
+            _member: PhantomData,
+        }
+    }
+
+    fn stop(&mut self) {
+        self.pending.clear();
+        self.next_page = None;
+    }
+}
+
+impl<T> Iterator for Members<'_, T>
+where
+    T: DeserializeOwned + ::std::fmt::Debug,
+{
+    type Item = Result<T, RedfishError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(path) = self.pending.pop_front() {
+                let member = self.redfish.get(&path);
+                if member.is_err() {
+                    self.stop();
+                }
+                return Some(member);
+            }
+            let page_path = self.next_page.take()?;
+            let page: Collection = match self
+                .guard
+                .visit(&page_path)
+                .and_then(|_| self.redfish.get(&page_path))
+            {
+                Ok(p) => p,
+                Err(e) => {
+                    self.stop();
+                    return Some(Err(e));
+                }
+            };
+            self.pending = page.members.iter().map(|m| m.path().to_string()).collect();
+            self.next_page = page.next_link.map(|l| relative_path(&l).to_string());
+        }
+    }
+}
+
+/// The most pages `Redfish::get_all` follows before giving up on a collection
+pub const MAX_PAGES: usize = 1000;
+
+/// One page of a collection whose members are deserialized as they are embedded
+#[derive(Debug, Deserialize)]
+pub(crate) struct Page<T> {
+    #[serde(rename = "Members", default = "Vec::new")]
+    pub(crate) members: Vec<T>,
+    #[serde(rename = "Members@odata.nextLink")]
+    pub(crate) next_link: Option<String>,
+}
+
+/// Tracks the pages of one collection walk, refusing links that repeat or a
+/// walk that runs past `MAX_PAGES`
+pub(crate) struct PageGuard {
+    collection: String,
+    seen: HashSet<String>,
+}
+
+impl PageGuard {
+    pub(crate) fn new(collection: &str) -> Self {
+        PageGuard {
+            collection: collection.to_string(),
+            seen: HashSet::new(),
+        }
+    }
+
+    pub(crate) fn visit(&mut self, link: &str) -> Result<(), RedfishError> {
+        if self.seen.len() >= MAX_PAGES {
+            return Err(RedfishError::Pagination {
+                collection: self.collection.clone(),
+                detail: format!("more than {} pages", MAX_PAGES),
+            });
+        }
+        if !self.seen.insert(link.to_string()) {
+            return Err(RedfishError::Pagination {
+                collection: self.collection.clone(),
+                detail: format!("page {} was already visited", link),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_page_guard() {
+    let mut guard = PageGuard::new("Systems/1/LogServices/SEL/Entries/");
+    assert!(guard.visit("Systems/1/LogServices/SEL/Entries/").is_ok());
+    assert!(
+        guard
+            .visit("Systems/1/LogServices/SEL/Entries?$skip=50")
+            .is_ok()
+    );
+    assert!(matches!(
+        guard.visit("Systems/1/LogServices/SEL/Entries?$skip=50"),
+        Err(RedfishError::Pagination { .. })
+    ));
+
+    let mut guard = PageGuard::new("Tasks/");
+    for page in 0..MAX_PAGES {
+        assert!(guard.visit(&format!("Tasks?$skip={}", page)).is_ok());
+    }
+    assert!(guard.visit("Tasks?$skip=last").is_err());
+}
+
+#[test]
+fn test_page_parser() {
+    let test_data = include_str!("../tests/resource-blocks.json");
+    let result: Page<ODataId> = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.members.len(), 2);
+    assert!(result.next_link.is_none());
+}
@@ -1,6 +1,7 @@
 use crate::common::*;
+use crate::error::RedfishError;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct OemHpSnmppowerthresholdalert {
     pub duration_in_min: i64,
@@ -8,7 +9,7 @@ pub struct OemHpSnmppowerthresholdalert {
     pub trigger: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct OemHp {
     #[serde(flatten)]
     pub oem_type: HpType,
@@ -18,19 +19,19 @@ pub struct OemHp {
     pub links: LinkType,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Oem {
     pub hp: OemHp,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct PowercontrolPowerlimit {
     pub limit_in_watts: Option<i64>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct PowercontrolPowermetric {
     pub average_consumed_watts: i64,
@@ -39,7 +40,7 @@ pub struct PowercontrolPowermetric {
     pub min_consumed_watts: i64,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Powercontrol {
     pub power_capacity_watts: i64,
@@ -48,13 +49,13 @@ pub struct Powercontrol {
     pub power_metrics: PowercontrolPowermetric,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct PowersuppliesOemHpPowersupplystatus {
     pub state: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct PowersuppliesOemHp {
     #[serde(flatten)]
@@ -69,13 +70,13 @@ pub struct PowersuppliesOemHp {
     pub i_pdu_capable: bool,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct PowersuppliesOem {
     pub hp: PowersuppliesOemHp,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Powersupply {
     pub firmware_version: String,
@@ -90,7 +91,7 @@ pub struct Powersupply {
     pub serial_number: String,
     pub spare_part_number: String,
 
-    pub status: AllStatus,
+    pub status: ResourceStatus,
 }
 
 impl Status for Powersupply {
@@ -103,7 +104,7 @@ impl Status for Powersupply {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Redundancy {
     pub max_num_supported: i64,
@@ -114,7 +115,7 @@ pub struct Redundancy {
     pub redundancy_set: Vec<ODataId>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Power {
     #[serde(flatten)]
@@ -133,6 +134,34 @@ pub struct Power {
     pub power_type: String,
 }
 
+impl Power {
+    /// The sum of `PowerConsumedWatts` over every `PowerControl` entry, or
+    /// `None` when the chassis reports no power control at all
+    pub fn total_consumed_watts(&self) -> Option<f64> {
+        if self.power_control.is_empty() {
+            return None;
+        }
+        Some(
+            self.power_control
+                .iter()
+                .map(|c| c.power_consumed_watts as f64)
+                .sum(),
+        )
+    }
+
+    /// Whether every installed power supply reports `OK` health. Empty bays
+    /// (`Absent`) are skipped, and a chassis reporting no supplies at all is
+    /// not considered healthy.
+    pub fn redundancy_healthy(&self) -> bool {
+        let mut installed = self
+            .power_supplies
+            .iter()
+            .filter(|p| p.status.state != Some(State::Absent))
+            .peekable();
+        installed.peek().is_some() && installed.all(|p| p.status.health == Some(Health::Ok))
+    }
+}
+
 impl StatusVec for Power {
     fn get_vec(&self) -> Vec<Box<dyn Status>> {
         let mut v: Vec<Box<dyn Status>> = Vec::new();
@@ -149,3 +178,180 @@ fn test_power_parser() {
     let result: Power = serde_json::from_str(&test_data).unwrap();
     println!("result: {:#?}", result);
 }
+
+#[test]
+fn test_power_aggregates() {
+    let test_data = include_str!("../tests/power.json");
+    let mut json: serde_json::Value = serde_json::from_str(&test_data).unwrap();
+    let result: Power = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(result.total_consumed_watts(), Some(590.0));
+    assert!(result.redundancy_healthy());
+
+    json["PowerSupplies"][1]["Status"]["Health"] = serde_json::json!("Critical");
+    let result: Power = serde_json::from_value(json.clone()).unwrap();
+    assert!(!result.redundancy_healthy());
+
+    json["PowerSupplies"][1]["Status"]["State"] = serde_json::json!("Absent");
+    let result: Power = serde_json::from_value(json.clone()).unwrap();
+    assert!(result.redundancy_healthy());
+
+    json["PowerControl"] = serde_json::json!([]);
+    json["PowerSupplies"] = serde_json::json!([]);
+    let result: Power = serde_json::from_value(json).unwrap();
+    assert_eq!(result.total_consumed_watts(), None);
+    assert!(!result.redundancy_healthy());
+}
+
+/// A backup battery from the newer `PowerSubsystem/Batteries` collection
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Battery {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub capacity_actual_amp_hours: Option<f64>,
+    pub capacity_actual_watt_hours: Option<f64>,
+    pub capacity_rated_amp_hours: Option<f64>,
+    pub capacity_rated_watt_hours: Option<f64>,
+    /// `Idle`, `Charging` or `Discharging`
+    pub charge_state: Option<String>,
+    pub firmware_version: Option<String>,
+    pub id: String,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub name: String,
+    pub part_number: Option<String>,
+    pub serial_number: Option<String>,
+    pub state_of_health_percent: Option<SensorExcerpt>,
+    pub status: Option<ResourceStatus>,
+}
+
+#[test]
+fn test_battery_parser() {
+    let test_data = include_str!("../tests/battery.json");
+    let result: Battery = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
+
+/// The consumption a chassis has seen, bounding a sensible power cap
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct PowerLimitMetrics {
+    pub max_consumed_watts: Option<i64>,
+    pub min_consumed_watts: Option<i64>,
+}
+
+/// The cap related fields of a `PowerControl` entry, which unlike the rest of
+/// `Power` are reported the same way across vendors
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct PowerLimitControl {
+    pub power_capacity_watts: Option<i64>,
+    pub power_limit: Option<PowercontrolPowerlimit>,
+    pub power_metrics: Option<PowerLimitMetrics>,
+}
+
+impl PowerLimitControl {
+    /// Check a cap lies between the lowest consumption the chassis has
+    /// reported, which it could never be held under, and its capacity.
+    /// Bounds the BMC does not report are not checked.
+    pub fn check_limit(&self, limit_watts: u32) -> Result<(), RedfishError> {
+        let min = self
+            .power_metrics
+            .as_ref()
+            .and_then(|m| m.min_consumed_watts);
+        let max = self.power_capacity_watts;
+        let watts = i64::from(limit_watts);
+        if min.is_some_and(|min| watts < min) || max.is_some_and(|max| watts > max) {
+            return Err(RedfishError::InvalidValue {
+                property: "PowerLimit.LimitInWatts".to_string(),
+                value: limit_watts.to_string(),
+                allowed: vec![format!(
+                    "{} to {} watts",
+                    min.map_or("0".to_string(), |m| m.to_string()),
+                    max.map_or("any".to_string(), |m| m.to_string())
+                )],
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct PowerLimitControls {
+    #[serde(default)]
+    pub power_control: Vec<PowerLimitControl>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SetPowerControl {
+    pub power_limit: PowercontrolPowerlimit,
+}
+
+/// The body PATCHed to change a chassis's cap, through its first `PowerControl`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SetPowerLimit {
+    pub power_control: Vec<SetPowerControl>,
+}
+
+impl SetPowerLimit {
+    /// Cap at `limit_watts`, or lift the cap with `None`
+    pub fn new(limit_watts: Option<u32>) -> Self {
+        SetPowerLimit {
+            power_control: vec![SetPowerControl {
+                power_limit: PowercontrolPowerlimit {
+                    limit_in_watts: limit_watts.map(i64::from),
+                },
+            }],
+        }
+    }
+}
+
+#[test]
+fn test_power_limit() {
+    let test_data = include_str!("../tests/power.json");
+    let result: PowerLimitControls = serde_json::from_str(&test_data).unwrap();
+    let control = &result.power_control[0];
+    assert!(
+        control
+            .power_limit
+            .as_ref()
+            .unwrap()
+            .limit_in_watts
+            .is_none()
+    );
+    assert!(control.check_limit(600).is_ok());
+    assert!(control.check_limit(1600).is_ok());
+    assert!(matches!(
+        control.check_limit(1601),
+        Err(RedfishError::InvalidValue { .. })
+    ));
+    assert!(control.check_limit(500).is_err());
+
+    assert_eq!(
+        serde_json::to_value(SetPowerLimit::new(Some(600))).unwrap(),
+        serde_json::json!({ "PowerControl": [{ "PowerLimit": { "LimitInWatts": 600 } }] })
+    );
+    assert_eq!(
+        serde_json::to_value(SetPowerLimit::new(None)).unwrap(),
+        serde_json::json!({ "PowerControl": [{ "PowerLimit": { "LimitInWatts": null } }] })
+    );
+}
+
+/// The `Power` resource of a chassis, relative to the service root
+pub(crate) fn power_path(chassis_id: &str) -> String {
+    format!("Chassis/{}/Power/", chassis_id)
+}
+
+#[test]
+fn test_power_path() {
+    assert_eq!(power_path("1"), "Chassis/1/Power/");
+    assert_eq!(power_path("Enclosure.2"), "Chassis/Enclosure.2/Power/");
+}
+
+#[test]
+fn test_power_roundtrip() {
+    assert_roundtrip::<Power>(include_str!("../tests/power.json"));
+}
@@ -0,0 +1,84 @@
+use crate::common::*;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Processor {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub id: String,
+    /// e.g. `x86-64`, `ARM-A64`
+    pub instruction_set: Option<String>,
+    #[serde(rename = "MaxSpeedMHz")]
+    pub max_speed_mhz: Option<i64>,
+    pub model: Option<String>,
+    pub name: String,
+    pub processor_type: Option<String>,
+    pub socket: Option<String>,
+    pub status: Option<SomeStatus>,
+    pub total_cores: Option<i64>,
+    pub total_threads: Option<i64>,
+}
+
+#[test]
+fn test_processor_parser() {
+    let test_data = include_str!("../tests/processor.json");
+    let result: Processor = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
+
+pub type Processors = Collection;
+
+#[test]
+fn test_processors_parser() {
+    let test_data = include_str!("../tests/processors.json");
+    let result: Processors = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ProcessorMetrics {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub consumed_power_watt: Option<f64>,
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "OperatingSpeedMHz")]
+    pub operating_speed_mhz: Option<f64>,
+    pub temperature_celsius: Option<f64>,
+    /// The margin in degrees before the processor starts throttling
+    pub throttling_celsius: Option<f64>,
+}
+
+impl ProcessorMetrics {
+    /// Whether the processor has used up its thermal margin and is throttling
+    pub fn is_throttled(&self) -> bool {
+        self.throttling_celsius.is_some_and(|margin| margin <= 0.0)
+    }
+}
+
+#[test]
+fn test_processor_metrics_parser() {
+    let test_data = include_str!("../tests/processor-metrics.json");
+    let result: ProcessorMetrics = serde_json::from_str(&test_data).unwrap();
+    assert!(!result.is_throttled());
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_processor_throttled() {
+    let metrics = |margin: Option<f64>| -> ProcessorMetrics {
+        serde_json::from_value(serde_json::json!({
+            "@odata.id": "/redfish/v1/Systems/1/Processors/CPU1/ProcessorMetrics",
+            "@odata.type": "#ProcessorMetrics.v1_4_0.ProcessorMetrics",
+            "Id": "ProcessorMetrics",
+            "Name": "Processor Metrics",
+            "ThrottlingCelsius": margin,
+        }))
+        .unwrap()
+    };
+    assert!(metrics(Some(0.0)).is_throttled());
+    assert!(metrics(Some(-3.0)).is_throttled());
+    assert!(!metrics(Some(12.0)).is_throttled());
+    assert!(!metrics(None).is_throttled());
+}
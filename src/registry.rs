@@ -0,0 +1,170 @@
+use crate::common::*;
+use std::collections::HashMap;
+
+/// Where a copy of a registry can be fetched from, for one language
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RegistryLocation {
+    pub archive_uri: Option<String>,
+    pub language: Option<String>,
+    pub publication_uri: Option<String>,
+    /// A copy of the registry hosted by the service itself
+    pub uri: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageRegistryFile {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub id: String,
+    #[serde(default)]
+    pub languages: Vec<String>,
+    #[serde(default)]
+    pub location: Vec<RegistryLocation>,
+    pub name: String,
+    /// The registry prefix and version, e.g. `Base.1.15`
+    pub registry: String,
+}
+
+/// Where `MessageRegistryFile::preferred_location` found a copy of a registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegistrySource<'a> {
+    /// Hosted by the service, relative to the service root once trimmed
+    Hosted(&'a str),
+    /// Published elsewhere, typically by the DMTF or the vendor
+    Published(&'a str),
+}
+
+impl MessageRegistryFile {
+    /// The location to read the registry from, preferring English and then a
+    /// copy hosted by the service over a published one
+    pub fn preferred_location(&self) -> Option<RegistrySource<'_>> {
+        self.location
+            .iter()
+            .filter_map(|l| {
+                let english = l.language.as_deref().is_none_or(|lang| lang == "en");
+                match (&l.uri, &l.publication_uri) {
+                    (Some(uri), _) => Some(((!english, false), RegistrySource::Hosted(uri))),
+                    (None, Some(uri)) => Some(((!english, true), RegistrySource::Published(uri))),
+                    (None, None) => None,
+                }
+            })
+            .min_by_key(|(rank, _)| *rank)
+            .map(|(_, source)| source)
+    }
+}
+
+#[test]
+fn test_message_registry_file_parser() {
+    let test_data = include_str!("../tests/message-registry-file.json");
+    let result: MessageRegistryFile = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(
+        result.preferred_location(),
+        Some(RegistrySource::Hosted(
+            "/redfish/v1/registries/Base.1.15.0.json"
+        ))
+    );
+    println!("result: {:#?}", result);
+}
+
+pub type Registries = Collection;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Message {
+    pub description: Option<String>,
+    /// The message text, with `%1`, `%2`... standing in for its arguments
+    pub message: String,
+    #[serde(default)]
+    pub number_of_args: u32,
+    #[serde(default)]
+    pub param_types: Vec<String>,
+    pub resolution: Option<String>,
+    pub severity: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageRegistry {
+    #[serde(rename = "@odata.type")]
+    pub odata_type: Option<String>,
+    pub id: String,
+    pub language: Option<String>,
+    pub messages: HashMap<String, Message>,
+    pub name: String,
+    pub owning_entity: Option<String>,
+    pub registry_prefix: String,
+    pub registry_version: String,
+}
+
+#[test]
+fn test_message_registry_parser() {
+    let test_data = include_str!("../tests/message-registry.json");
+    let result: MessageRegistry = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.messages["PropertyUnknown"].number_of_args, 1);
+    println!("result: {:#?}", result);
+}
+
+/// One of the named values an enumeration attribute accepts
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct AttributeValue {
+    pub value_display_name: Option<String>,
+    pub value_name: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct AttributeEntry {
+    pub attribute_name: String,
+    pub display_name: Option<String>,
+    pub lower_bound: Option<i64>,
+    pub read_only: Option<bool>,
+    pub reset_required: Option<bool>,
+    /// e.g. `Enumeration`, `String`, `Integer`, `Boolean`
+    #[serde(rename = "Type")]
+    pub attribute_type: Option<String>,
+    pub upper_bound: Option<i64>,
+    #[serde(default)]
+    pub value: Vec<AttributeValue>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RegistryEntries {
+    #[serde(default)]
+    pub attributes: Vec<AttributeEntry>,
+}
+
+/// The registry describing the type and constraints of each attribute of a
+/// resource such as `Bios`
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct AttributeRegistry {
+    #[serde(rename = "@odata.type")]
+    pub odata_type: Option<String>,
+    pub id: String,
+    pub language: Option<String>,
+    pub name: String,
+    pub owning_entity: Option<String>,
+    pub registry_entries: RegistryEntries,
+    pub registry_version: String,
+}
+
+impl AttributeRegistry {
+    pub fn attribute(&self, name: &str) -> Option<&AttributeEntry> {
+        self.registry_entries
+            .attributes
+            .iter()
+            .find(|a| a.attribute_name == name)
+    }
+}
+
+#[test]
+fn test_attribute_registry_parser() {
+    let test_data = include_str!("../tests/bios-attribute-registry.json");
+    let result: AttributeRegistry = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.attribute("BootMode").unwrap().value.len(), 2);
+    println!("result: {:#?}", result);
+}
@@ -0,0 +1,77 @@
+use crate::error::RedfishError;
+use std::time::Duration;
+
+/// How often to retry a request that failed in a way that may clear up on its
+/// own, such as a busy BMC answering 503 or dropping the connection. Only
+/// requests that are safe to repeat (GET and HEAD) are retried, and 4xx
+/// responses never are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first, so 1 disables retrying
+    pub max_attempts: u32,
+    /// The wait before the first retry, doubled before each one after
+    pub base_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Never retry
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            base_backoff: Duration::ZERO,
+        }
+    }
+
+    /// The wait before retry number `retry`, counting from 1
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.base_backoff
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+    }
+
+    /// Whether `err` is the kind of failure worth another attempt: a server or
+    /// gateway error that may be temporary, no connection, or no answer
+    pub fn should_retry(&self, err: &RedfishError) -> bool {
+        match err {
+            RedfishError::UnexpectedStatus { status, .. } => {
+                matches!(status, 500 | 502 | 503 | 504)
+            }
+            RedfishError::ConnectTimeout(_) | RedfishError::ConnectionRefused(_) => true,
+            err => err.no_response(),
+        }
+    }
+}
+
+/// Three attempts, waiting 500ms and then 1s between them
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_backoff: Duration::from_millis(500),
+        }
+    }
+}
+
+#[test]
+fn test_retry_backoff() {
+    let policy = RetryPolicy::default();
+    assert_eq!(policy.backoff(1), Duration::from_millis(500));
+    assert_eq!(policy.backoff(2), Duration::from_secs(1));
+    assert_eq!(policy.backoff(3), Duration::from_secs(2));
+    assert_eq!(RetryPolicy::none().max_attempts, 1);
+}
+
+#[test]
+fn test_retry_classification() {
+    let policy = RetryPolicy::default();
+    let status = |status: u16| RedfishError::UnexpectedStatus {
+        status,
+        body: String::new(),
+    };
+    assert!(policy.should_retry(&status(503)));
+    assert!(policy.should_retry(&status(500)));
+    assert!(!policy.should_retry(&status(501)));
+    assert!(!policy.should_retry(&status(505)));
+    assert!(!policy.should_retry(&status(404)));
+    assert!(!policy.should_retry(&status(401)));
+    assert!(!policy.should_retry(&RedfishError::NotSupported("x".to_string())));
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+/// A credential that is redacted from `Debug` output and, with the `zeroize`
+/// feature, wiped from memory when dropped. It deliberately has no `Clone` or
+/// `Display`, so copies only exist where the auth path needs them.
+#[derive(Default)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Secret(secret.into())
+    }
+
+    /// The secret itself, for putting on the wire
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+/// For `serialize_with` on the request bodies that have to carry a secret
+pub(crate) fn serialize_exposed<S: serde::Serializer>(
+    secret: &Secret,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(secret.expose())
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Secret(***)")
+    }
+}
+
+impl From<String> for Secret {
+    fn from(secret: String) -> Self {
+        Secret(secret)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(secret: &str) -> Self {
+        Secret(secret.to_string())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Secret {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0);
+    }
+}
+
+#[test]
+fn test_secret_redacted() {
+    let secret = Secret::from("hunter2");
+    assert_eq!(secret.expose(), "hunter2");
+    assert_eq!(format!("{:?}", secret), "Secret(***)");
+    assert_eq!(format!("{:?}", Some(secret)), "Some(Secret(***))");
+}
@@ -0,0 +1,38 @@
+use crate::common::*;
+
+/// The UEFI Secure Boot settings of a system. `secure_boot_enable` is what
+/// is configured, `secure_boot_current_boot` whether the running boot was
+/// actually verified; they differ until the system reboots after a change.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SecureBoot {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub id: String,
+    pub name: String,
+    pub secure_boot_current_boot: Option<String>,
+    pub secure_boot_enable: Option<bool>,
+    pub secure_boot_mode: Option<String>,
+}
+
+impl SecureBoot {
+    /// Whether the current boot was verified by Secure Boot
+    pub fn is_active(&self) -> bool {
+        self.secure_boot_current_boot.as_deref() == Some("Enabled")
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SetSecureBoot {
+    pub secure_boot_enable: bool,
+}
+
+#[test]
+fn test_secure_boot_parser() {
+    let test_data = include_str!("../tests/secure-boot.json");
+    let result: SecureBoot = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.secure_boot_enable, Some(true));
+    assert!(!result.is_active());
+    println!("result: {:#?}", result);
+}
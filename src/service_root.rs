@@ -0,0 +1,32 @@
+use crate::common::*;
+
+/// The entry point of a Redfish service, linking to the top-level collections
+/// so their paths can be followed instead of assumed
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ServiceRoot {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub account_service: Option<ODataId>,
+    pub chassis: Option<ODataId>,
+    pub id: String,
+    pub managers: Option<ODataId>,
+    pub name: String,
+    pub redfish_version: Option<String>,
+    pub registries: Option<ODataId>,
+    pub session_service: Option<ODataId>,
+    pub systems: Option<ODataId>,
+    pub update_service: Option<ODataId>,
+    #[serde(rename = "UUID")]
+    pub uuid: Option<String>,
+    pub vendor: Option<String>,
+}
+
+#[test]
+fn test_service_root_parser() {
+    let test_data = include_str!("../tests/service-root.json");
+    let result: ServiceRoot = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.systems.as_ref().unwrap().path(), "Systems");
+    assert_eq!(result.redfish_version.as_deref(), Some("1.13.0"));
+    println!("result: {:#?}", result);
+}
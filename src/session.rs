@@ -0,0 +1,58 @@
+use crate::common::*;
+use crate::secret::Secret;
+
+/// The header a Redfish session token is carried in
+pub const X_AUTH_TOKEN: &str = "X-Auth-Token";
+
+/// A session created through the `SessionService`
+#[derive(Debug)]
+pub struct SessionToken {
+    pub token: Secret,
+    /// The session resource, which is deleted to log out
+    pub location: Option<String>,
+}
+
+/// The sessions open against the `SessionService`, including other clients'
+pub type SessionCollection = Collection;
+
+/// The login body; its `Debug` output leaves the password out
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SessionCredentials<'a> {
+    pub user_name: &'a str,
+    pub password: &'a str,
+}
+
+impl std::fmt::Debug for SessionCredentials<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("SessionCredentials")
+            .field("user_name", &self.user_name)
+            .field("password", &"***")
+            .finish()
+    }
+}
+
+#[test]
+fn test_session_credentials() {
+    let credentials = SessionCredentials {
+        user_name: "admin",
+        password: "hunter2",
+    };
+    assert_eq!(
+        serde_json::to_value(&credentials).unwrap(),
+        serde_json::json!({ "UserName": "admin", "Password": "hunter2" })
+    );
+    assert!(!format!("{:?}", credentials).contains("hunter2"));
+}
+
+#[test]
+fn test_session_collection_parser() {
+    let test_data = include_str!("../tests/sessions.json");
+    let result: SessionCollection = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.members.len(), 2);
+    assert_eq!(
+        result.members[1].path(),
+        "SessionService/Sessions/admin5e1c9a2b"
+    );
+    println!("result: {:#?}", result);
+}
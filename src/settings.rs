@@ -0,0 +1,96 @@
+use crate::common::*;
+use serde_json::Value;
+
+/// Properties that describe a settings object rather than a staged change
+const IGNORED_PROPERTIES: &[&str] = &["Id", "Name", "Description", "Oem"];
+
+/// A resource with changes staged on its `@Redfish.Settings` object that have
+/// not been applied yet
+#[derive(Debug, Clone)]
+pub struct PendingChange {
+    /// The resource the changes apply to, relative to the service root
+    pub resource: String,
+    /// The settings object holding the staged values, relative to the service root
+    pub settings_object: String,
+    /// The staged properties that differ from the current values, with nested
+    /// properties joined by `/`, e.g. `Attributes/ProcHyperthreading`
+    pub properties: Vec<String>,
+    /// Whether the changes only take effect once the system is reset
+    pub reset_required: bool,
+}
+
+/// Compare a resource against its staged settings object, returning `None` when
+/// nothing staged differs from what is currently applied
+pub(crate) fn pending_change(
+    resource: &str,
+    settings: &RedfishSettings,
+    current: &Value,
+    staged: &Value,
+) -> Option<PendingChange> {
+    let mut properties = Vec::new();
+    diff_properties("", current, staged, &mut properties);
+    if properties.is_empty() {
+        return None;
+    }
+    // An explicit apply time on the staged object wins over what the resource
+    // supports; with neither, vendors apply staged settings on reset
+    let reset_required = match staged["@Redfish.SettingsApplyTime"]["ApplyTime"].as_str() {
+        Some(apply_time) => apply_time != "Immediate",
+        None => !settings
+            .supported_apply_times
+            .iter()
+            .any(|t| t == "Immediate"),
+    };
+    Some(PendingChange {
+        resource: resource.to_string(),
+        settings_object: settings.settings_object.path().to_string(),
+        properties,
+        reset_required,
+    })
+}
+
+fn diff_properties(prefix: &str, current: &Value, staged: &Value, changed: &mut Vec<String>) {
+    let fields = match staged.as_object() {
+        Some(f) => f,
+        None => return,
+    };
+    for (key, value) in fields {
+        if key.starts_with('@')
+            || key.contains("@odata")
+            || IGNORED_PROPERTIES.contains(&key.as_str())
+        {
+            continue;
+        }
+        let path = format!("{}{}", prefix, key);
+        match (value, current.get(key)) {
+            (Value::Object(_), Some(existing @ Value::Object(_))) => {
+                diff_properties(&format!("{}/", path), existing, value, changed)
+            }
+            (_, Some(existing)) if existing == value => {}
+            _ => changed.push(path),
+        }
+    }
+}
+
+#[test]
+fn test_pending_change() {
+    let test_data = include_str!("../tests/bios.json");
+    let current: Value = serde_json::from_str(&test_data).unwrap();
+    let settings: RedfishSettings =
+        serde_json::from_value(current["@Redfish.Settings"].clone()).unwrap();
+
+    let mut staged = current.clone();
+    staged["@odata.id"] = serde_json::json!("/redfish/v1/Systems/1/Bios/Settings/");
+    staged["Name"] = serde_json::json!("BIOS Pending Settings");
+    assert!(pending_change("Systems/1/Bios/", &settings, &current, &staged).is_none());
+
+    staged["Attributes"]["ProcHyperthreading"] = serde_json::json!("Disabled");
+    let change = pending_change("Systems/1/Bios/", &settings, &current, &staged).unwrap();
+    assert_eq!(change.settings_object, "Systems/1/Bios/Settings/");
+    assert_eq!(change.properties, vec!["Attributes/ProcHyperthreading"]);
+    assert!(change.reset_required);
+
+    staged["@Redfish.SettingsApplyTime"] = serde_json::json!({ "ApplyTime": "Immediate" });
+    let change = pending_change("Systems/1/Bios/", &settings, &current, &staged).unwrap();
+    assert!(!change.reset_required);
+}
@@ -1,4 +1,7 @@
 use crate::common::*;
+/// Moved to `storage_standard` alongside the rest of the standard storage model
+pub use crate::storage_standard::Volume;
+
 pub trait Hardware {
     fn odata_context(&self) -> String;
     fn odata_id(&self) -> String;
@@ -11,7 +14,7 @@ pub trait Hardware {
     fn model(&self) -> String;
     fn name(&self) -> String;
     fn serial_number(&self) -> String;
-    fn status(&self) -> AllStatus;
+    fn status(&self) -> ResourceStatus;
     fn get_type(&self) -> HardwareType;
 }
 
@@ -23,7 +26,7 @@ pub enum HardwareType {
     StorageEnclosure,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct HardwareCommon {
     #[serde(flatten)]
@@ -36,10 +39,10 @@ pub struct HardwareCommon {
     pub model: String,
     pub name: String,
     pub serial_number: String,
-    pub status: AllStatus,
+    pub status: ResourceStatus,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct ArrayController {
     pub adapter_type: String,
@@ -62,11 +65,11 @@ pub struct ArrayController {
 }
 impl Status for ArrayController {
     fn health(&self) -> String {
-        self.hardware_common.status.health.to_owned()
+        self.hardware_common.status.health()
     }
 
     fn state(&self) -> String {
-        self.hardware_common.status.state.to_owned()
+        self.hardware_common.status.state()
     }
 }
 
@@ -104,7 +107,7 @@ impl Hardware for ArrayController {
     fn serial_number(&self) -> String {
         self.hardware_common.serial_number.to_owned()
     }
-    fn status(&self) -> AllStatus {
+    fn status(&self) -> ResourceStatus {
         self.hardware_common.status.to_owned()
     }
     fn get_type(&self) -> HardwareType {
@@ -119,7 +122,7 @@ fn test_array_controller_parser() {
     println!("result: {:#?}", result);
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct MultHardware {
     #[serde(flatten)]
@@ -133,7 +136,7 @@ pub struct MultHardware {
     pub total: i64,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct ArrayControllers {
     #[serde(flatten)]
@@ -149,7 +152,7 @@ fn test_array_controllers_parser() {
     println!("result: {:#?}", result);
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct SmartArray {
     pub adapter_type: String,
@@ -171,11 +174,11 @@ pub struct SmartArray {
 }
 impl Status for SmartArray {
     fn health(&self) -> String {
-        self.hardware_common.status.health.to_owned()
+        self.hardware_common.status.health()
     }
 
     fn state(&self) -> String {
-        self.hardware_common.status.state.to_owned()
+        self.hardware_common.status.state()
     }
 }
 
@@ -213,7 +216,7 @@ impl Hardware for SmartArray {
     fn serial_number(&self) -> String {
         self.hardware_common.serial_number.to_owned()
     }
-    fn status(&self) -> AllStatus {
+    fn status(&self) -> ResourceStatus {
         self.hardware_common.status.to_owned()
     }
     fn get_type(&self) -> HardwareType {
@@ -228,7 +231,7 @@ fn test_smart_array_parser() {
     println!("result: {:#?}", result);
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct StorageEnclosure {
     pub drive_bay_count: i64,
@@ -239,11 +242,11 @@ pub struct StorageEnclosure {
 }
 impl Status for StorageEnclosure {
     fn health(&self) -> String {
-        self.hardware_common.status.health.to_owned()
+        self.hardware_common.status.health()
     }
 
     fn state(&self) -> String {
-        self.hardware_common.status.state.to_owned()
+        self.hardware_common.status.state()
     }
 }
 
@@ -281,7 +284,7 @@ impl Hardware for StorageEnclosure {
     fn serial_number(&self) -> String {
         self.hardware_common.serial_number.to_owned()
     }
-    fn status(&self) -> AllStatus {
+    fn status(&self) -> ResourceStatus {
         self.hardware_common.status.to_owned()
     }
     fn get_type(&self) -> HardwareType {
@@ -296,7 +299,7 @@ fn test_storage_enclosure_parser() {
     println!("result: {:#?}", result);
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct StorageEnclosures {
     #[serde(flatten)]
@@ -312,7 +315,7 @@ fn test_storage_enclosures_parser() {
     println!("result: {:#?}", result);
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct DiskDrive {
     pub block_size_bytes: i64,
@@ -331,6 +334,8 @@ pub struct DiskDrive {
     pub interface_type: String,
     pub maximum_temperature_celsius: i64,
     pub media_type: String,
+    #[serde(default)]
+    pub operations: Vec<Operation>,
     pub power_on_hours: Option<i64>,
     pub rotational_speed_rpm: i64,
     pub ssd_endurance_utilization_percentage: Option<f64>,
@@ -339,11 +344,11 @@ pub struct DiskDrive {
 }
 impl Status for DiskDrive {
     fn health(&self) -> String {
-        self.hardware_common.status.health.to_owned()
+        self.hardware_common.status.health()
     }
 
     fn state(&self) -> String {
-        self.hardware_common.status.state.to_owned()
+        self.hardware_common.status.state()
     }
 }
 
@@ -381,7 +386,7 @@ impl Hardware for DiskDrive {
     fn serial_number(&self) -> String {
         self.hardware_common.serial_number.to_owned()
     }
-    fn status(&self) -> AllStatus {
+    fn status(&self) -> ResourceStatus {
         self.hardware_common.status.to_owned()
     }
     fn get_type(&self) -> HardwareType {
@@ -389,6 +394,29 @@ impl Hardware for DiskDrive {
     }
 }
 
+impl DiskDrive {
+    /// Whether the controller expects the drive to fail soon, from its SMART
+    /// or SSD wear-out status. A drive that has already failed is not counted.
+    pub fn is_predicted_to_fail(&self) -> bool {
+        self.disk_drive_status_reasons
+            .iter()
+            .any(|r| r == "PredictiveFailure" || r == "SSDWearOut")
+    }
+
+    /// The share of an SSD's rated write endurance still left, rounded down.
+    /// `None` for spinning drives and SSDs that do not report their wear.
+    pub fn life_remaining_percent(&self) -> Option<u8> {
+        self.ssd_endurance_utilization_percentage
+            .map(|used| (100.0 - used).clamp(0.0, 100.0) as u8)
+    }
+}
+
+impl Operations for DiskDrive {
+    fn operations(&self) -> &[Operation] {
+        &self.operations
+    }
+}
+
 #[test]
 fn test_storage_drive_parser() {
     let test_data = include_str!("../tests/disk-drive.json");
@@ -396,7 +424,7 @@ fn test_storage_drive_parser() {
     println!("result: {:#?}", result);
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct DiskDrives {
     #[serde(flatten)]
@@ -412,7 +440,7 @@ fn test_storage_drives_parser() {
     println!("result: {:#?}", result);
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct LogicalDrives {
     #[serde(flatten)]
@@ -433,3 +461,38 @@ fn test_storage_logical_drives_parser() {
     let result: LogicalDrives = serde_json::from_str(&test_data).unwrap();
     println!("result: {:#?}", result);
 }
+
+#[test]
+fn test_storage_roundtrip() {
+    assert_roundtrip::<ArrayController>(include_str!("../tests/array-controller.json"));
+    assert_roundtrip::<ArrayControllers>(include_str!("../tests/array-controllers.json"));
+    assert_roundtrip::<SmartArray>(include_str!("../tests/smart-array.json"));
+    assert_roundtrip::<StorageEnclosure>(include_str!("../tests/storage-enclosure.json"));
+    assert_roundtrip::<DiskDrive>(include_str!("../tests/disk-drive.json"));
+    assert_roundtrip::<LogicalDrives>(include_str!("../tests/logical-drives.json"));
+}
+
+#[test]
+fn test_disk_drive_health() {
+    let test_data = include_str!("../tests/disk-drive.json");
+    let mut json: serde_json::Value = serde_json::from_str(&test_data).unwrap();
+    let healthy: DiskDrive = serde_json::from_value(json.clone()).unwrap();
+    assert!(!healthy.is_predicted_to_fail());
+    assert_eq!(healthy.life_remaining_percent(), None);
+
+    json["DiskDriveStatusReasons"] = serde_json::json!(["PredictiveFailure"]);
+    json["Status"]["Health"] = serde_json::json!("Warning");
+    let failing: DiskDrive = serde_json::from_value(json.clone()).unwrap();
+    assert!(failing.is_predicted_to_fail());
+
+    json["MediaType"] = serde_json::json!("SSD");
+    json["DiskDriveStatusReasons"] = serde_json::json!(["SSDWearOut"]);
+    json["SSDEnduranceUtilizationPercentage"] = serde_json::json!(97.6);
+    let worn: DiskDrive = serde_json::from_value(json.clone()).unwrap();
+    assert!(worn.is_predicted_to_fail());
+    assert_eq!(worn.life_remaining_percent(), Some(2));
+
+    json["SSDEnduranceUtilizationPercentage"] = serde_json::json!(104.0);
+    let overrun: DiskDrive = serde_json::from_value(json).unwrap();
+    assert_eq!(overrun.life_remaining_percent(), Some(0));
+}
@@ -0,0 +1,222 @@
+//! The standard Redfish storage model under `Systems/{id}/Storage/`, as
+//! implemented by Dell, Lenovo and Supermicro among others. HP iLO's
+//! `SmartStorage` model is in `storage`.
+
+use crate::chassis::IndicatorLed;
+use crate::common::*;
+
+/// A RAID or HBA controller, as embedded in its `Storage` subsystem
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct StorageController {
+    pub firmware_version: Option<String>,
+    pub manufacturer: Option<String>,
+    pub member_id: Option<String>,
+    pub model: Option<String>,
+    pub name: Option<String>,
+    pub serial_number: Option<String>,
+    pub speed_gbps: Option<f64>,
+    pub status: Option<ResourceStatus>,
+    #[serde(default, rename = "SupportedRAIDTypes")]
+    pub supported_raid_types: Vec<String>,
+}
+
+/// A storage subsystem: its controllers, and links to its drives and volumes
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Storage {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    #[serde(default)]
+    pub drives: Vec<ODataId>,
+    pub id: String,
+    pub name: String,
+    pub status: Option<ResourceStatus>,
+    #[serde(default)]
+    pub storage_controllers: Vec<StorageController>,
+    pub volumes: Option<ODataId>,
+}
+
+pub type StorageCollection = Collection;
+
+/// A physical disk attached to a storage subsystem
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Drive {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub capacity_bytes: Option<i64>,
+    pub failure_predicted: Option<bool>,
+    pub id: String,
+    #[serde(rename = "IndicatorLED")]
+    pub indicator_led: Option<String>,
+    pub manufacturer: Option<String>,
+    pub media_type: Option<String>,
+    pub model: Option<String>,
+    pub name: String,
+    pub predicted_media_life_left_percent: Option<f64>,
+    pub protocol: Option<String>,
+    pub serial_number: Option<String>,
+    pub status: Option<ResourceStatus>,
+}
+
+impl Drive {
+    /// The state of the drive bay's locate LED, if the drive reports one
+    pub fn indicator_led(&self) -> Option<IndicatorLed> {
+        self.indicator_led.as_deref().and_then(IndicatorLed::parse)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Volume {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub capacity_bytes: Option<i64>,
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub operations: Vec<Operation>,
+    #[serde(rename = "RAIDType")]
+    pub raid_type: Option<String>,
+    pub status: Option<ResourceStatus>,
+}
+
+impl Operations for Volume {
+    fn operations(&self) -> &[Operation] {
+        &self.operations
+    }
+}
+
+pub type VolumeCollection = Collection;
+
+/// The RAID levels a volume can be created with. A controller lists the
+/// ones it supports in `StorageController::supported_raid_types`.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum RaidType {
+    #[serde(rename = "RAID0")]
+    Raid0,
+    #[serde(rename = "RAID1")]
+    Raid1,
+    #[serde(rename = "RAID5")]
+    Raid5,
+    #[serde(rename = "RAID6")]
+    Raid6,
+    #[serde(rename = "RAID10")]
+    Raid10,
+    #[serde(rename = "RAID50")]
+    Raid50,
+    #[serde(rename = "RAID60")]
+    Raid60,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct VolumeLinks {
+    pub drives: Vec<ODataId>,
+}
+
+/// The body POSTed to a `Volumes` collection to create a volume
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct VolumeCreateRequest {
+    #[serde(rename = "RAIDType")]
+    pub raid_type: RaidType,
+    pub links: VolumeLinks,
+    /// The whole of the drives when not given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity_bytes: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl VolumeCreateRequest {
+    /// A volume of `raid_type` across `drives`, e.g. `Storage::drives` or
+    /// the `odata` of drives from `Redfish::get_drives`
+    pub fn new(raid_type: RaidType, drives: Vec<ODataId>) -> Self {
+        VolumeCreateRequest {
+            raid_type,
+            links: VolumeLinks { drives },
+            capacity_bytes: None,
+            name: None,
+        }
+    }
+}
+
+#[test]
+fn test_storage_parser() {
+    let test_data = include_str!("../tests/storage.json");
+    let result: Storage = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.drives.len(), 2);
+    assert_eq!(
+        result.storage_controllers[0].supported_raid_types,
+        vec!["RAID0", "RAID1", "RAID5", "RAID10"]
+    );
+    assert_eq!(
+        result.volumes.as_ref().unwrap().path(),
+        "Systems/System.Embedded.1/Storage/RAID.Integrated.1-1/Volumes"
+    );
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_storage_collection_parser() {
+    let test_data = include_str!("../tests/storage-collection.json");
+    let result: StorageCollection = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.members.len(), 2);
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_drive_parser() {
+    let test_data = include_str!("../tests/drive.json");
+    let result: Drive = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.capacity_bytes, Some(959656755200));
+    assert_eq!(result.failure_predicted, Some(false));
+    assert_eq!(result.indicator_led(), Some(IndicatorLed::Off));
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_storage_volume_parser() {
+    let test_data = include_str!("../tests/volume.json");
+    let result: Volume = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(
+        result.in_progress_operations(),
+        vec![("Initialize".to_string(), Some(37))]
+    );
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_volume_collection_parser() {
+    let test_data = include_str!("../tests/volumes.json");
+    let result: VolumeCollection = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.members_count, Some(1));
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_storage_standard_roundtrip() {
+    assert_roundtrip::<Storage>(include_str!("../tests/storage.json"));
+    assert_roundtrip::<Drive>(include_str!("../tests/drive.json"));
+    assert_roundtrip::<Volume>(include_str!("../tests/volume.json"));
+}
+
+#[test]
+fn test_volume_create_request() {
+    let storage: Storage = serde_json::from_str(include_str!("../tests/storage.json")).unwrap();
+    let mut request = VolumeCreateRequest::new(RaidType::Raid1, storage.drives.clone());
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({
+            "RAIDType": "RAID1",
+            "Links": { "Drives": serde_json::to_value(&storage.drives).unwrap() }
+        })
+    );
+    request.name = Some("Boot".to_string());
+    request.capacity_bytes = Some(240057409536);
+    let body = serde_json::to_value(&request).unwrap();
+    assert_eq!(body["Name"], "Boot");
+    assert_eq!(body["CapacityBytes"], 240057409536i64);
+}
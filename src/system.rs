@@ -0,0 +1,246 @@
+use crate::RedfishError;
+use crate::common::*;
+
+/// The physical locate indicator state, normalized across the deprecated
+/// `IndicatorLED` enum and the newer `LocationIndicatorActive` boolean
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocateState {
+    On,
+    Off,
+    Blinking,
+    Unknown,
+}
+
+/// Whether a system is powered, as read from its `PowerState`, including the
+/// transitions while it powers on or off
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PowerState {
+    On,
+    Off,
+    PoweringOn,
+    PoweringOff,
+    Paused,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The kind of reset requested through `ComputerSystem.Reset`
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum ResetType {
+    On,
+    ForceOff,
+    GracefulShutdown,
+    GracefulRestart,
+    ForceRestart,
+    PowerCycle,
+    Nmi,
+    PushPowerButton,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Reset {
+    pub reset_type: ResetType,
+}
+
+/// How long a system waits before powering on, e.g. when AC power is restored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerOnDelay {
+    /// A fixed delay, where zero powers on immediately
+    Seconds(u32),
+    /// A random delay chosen by the firmware, to spread out a rack's inrush current
+    Random,
+}
+
+impl PowerOnDelay {
+    /// Parse `PowerOnDelaySeconds`, which the schema types as a number, into a
+    /// whole number of seconds
+    pub(crate) fn from_seconds(seconds: f64) -> Result<Self, RedfishError> {
+        if !seconds.is_finite() || seconds < 0.0 || seconds > u32::MAX as f64 {
+            return Err(RedfishError::InvalidValue {
+                property: "PowerOnDelaySeconds".to_string(),
+                value: seconds.to_string(),
+                allowed: vec![format!("0 to {}", u32::MAX)],
+            });
+        }
+        Ok(PowerOnDelay::Seconds(seconds as u32))
+    }
+}
+
+#[test]
+fn test_power_on_delay_from_seconds() {
+    assert_eq!(
+        PowerOnDelay::from_seconds(30.0).unwrap(),
+        PowerOnDelay::Seconds(30)
+    );
+    for seconds in [-1.0, f64::NAN, f64::INFINITY, 1e12] {
+        assert!(matches!(
+            PowerOnDelay::from_seconds(seconds),
+            Err(RedfishError::InvalidValue { .. })
+        ));
+    }
+}
+
+/// The system's power state along with what it does when power is restored
+#[derive(Debug, Clone)]
+pub struct PowerOnPolicy {
+    pub power_state: Option<PowerState>,
+    /// e.g. `AlwaysOn`, `AlwaysOff` or `LastState`
+    pub power_restore_policy: Option<String>,
+    pub delay: Option<PowerOnDelay>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ProcessorSummary {
+    pub count: Option<i64>,
+    pub model: Option<String>,
+    pub status: Option<ResourceStatus>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MemorySummary {
+    pub status: Option<ResourceStatus>,
+    #[serde(rename = "TotalSystemMemoryGiB")]
+    pub total_system_memory_gib: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ComputerSystem {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub id: String,
+    pub name: String,
+    pub bios_version: Option<String>,
+    #[serde(rename = "IndicatorLED")]
+    pub indicator_led: Option<String>,
+    pub last_reset_time: Option<String>,
+    pub location_indicator_active: Option<bool>,
+    pub manufacturer: Option<String>,
+    pub memory_summary: Option<MemorySummary>,
+    pub model: Option<String>,
+    pub power_on_delay_seconds: Option<f64>,
+    pub power_restore_policy: Option<String>,
+    pub power_state: Option<PowerState>,
+    pub processor_summary: Option<ProcessorSummary>,
+    pub serial_number: Option<String>,
+    #[serde(rename = "SKU")]
+    pub sku: Option<String>,
+    pub status: Option<ResourceStatus>,
+}
+
+pub type SystemCollection = Collection;
+
+impl ComputerSystem {
+    /// When the system was last reset, `None` if the firmware does not report it
+    #[cfg(feature = "time")]
+    pub fn last_reset(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.last_reset_time.as_deref().and_then(parse_timestamp)
+    }
+
+    /// Time elapsed since the system was last reset, `None` if the firmware
+    /// does not report a reset time
+    #[cfg(feature = "time")]
+    pub fn uptime(&self) -> Option<chrono::Duration> {
+        self.last_reset().map(|reset| chrono::Utc::now() - reset)
+    }
+
+    /// Firmware may populate either field, or both. `IndicatorLED` is checked first
+    /// since it is the only one able to report blinking.
+    pub fn locate_state(&self) -> LocateState {
+        match self.indicator_led.as_deref() {
+            Some("Lit") => return LocateState::On,
+            Some("Blinking") => return LocateState::Blinking,
+            Some("Off") => return LocateState::Off,
+            _ => {}
+        }
+        match self.location_indicator_active {
+            Some(true) => LocateState::On,
+            Some(false) => LocateState::Off,
+            None => LocateState::Unknown,
+        }
+    }
+}
+
+#[test]
+fn test_system_parser() {
+    let test_data = include_str!("../tests/system.json");
+    let result: ComputerSystem = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+    assert_eq!(result.locate_state(), LocateState::Off);
+    assert_eq!(result.serial_number.as_deref(), Some("MXQ91100WX"));
+    assert_eq!(result.sku.as_deref(), Some("867959-B21"));
+    assert_eq!(result.processor_summary.as_ref().unwrap().count, Some(2));
+    assert_eq!(
+        result
+            .memory_summary
+            .as_ref()
+            .unwrap()
+            .total_system_memory_gib,
+        Some(256.0)
+    );
+    assert_eq!(result.odata.odata_etag.as_deref(), Some("W/\"8F6CE0B3\""));
+}
+
+#[test]
+fn test_system_collection_parser() {
+    let test_data = include_str!("../tests/systems.json");
+    let result: SystemCollection = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.members[0].path(), "Systems/1/");
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_locate_state_normalization() {
+    let system = |led: Option<&str>, active: Option<bool>| -> ComputerSystem {
+        serde_json::from_value(serde_json::json!({
+            "@odata.id": "/redfish/v1/Systems/1/",
+            "@odata.type": "#ComputerSystem.v1_13_0.ComputerSystem",
+            "Id": "1",
+            "Name": "Computer System",
+            "IndicatorLED": led,
+            "LocationIndicatorActive": active,
+        }))
+        .unwrap()
+    };
+    assert_eq!(system(Some("Blinking"), Some(true)).locate_state(), LocateState::Blinking);
+    assert_eq!(system(Some("Lit"), None).locate_state(), LocateState::On);
+    assert_eq!(system(None, Some(true)).locate_state(), LocateState::On);
+    assert_eq!(system(None, Some(false)).locate_state(), LocateState::Off);
+    assert_eq!(system(Some("Unknown"), None).locate_state(), LocateState::Unknown);
+    assert_eq!(system(None, None).locate_state(), LocateState::Unknown);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_system_uptime() {
+    let test_data = include_str!("../tests/system.json");
+    let result: ComputerSystem = serde_json::from_str(&test_data).unwrap();
+    let last_reset = result.last_reset().unwrap();
+    assert_eq!(last_reset.to_rfc3339(), "2026-09-28T14:02:11+00:00");
+    assert!(result.uptime().is_some());
+}
+
+#[test]
+fn test_system_power_state() {
+    let test_data = include_str!("../tests/system.json");
+    let mut json: serde_json::Value = serde_json::from_str(&test_data).unwrap();
+    let result: ComputerSystem = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(result.power_state, Some(PowerState::On));
+    json["PowerState"] = serde_json::json!("Resetting");
+    let result: ComputerSystem = serde_json::from_value(json).unwrap();
+    assert_eq!(result.power_state, Some(PowerState::Unknown));
+}
+
+#[test]
+fn test_reset_body() {
+    let body = Reset {
+        reset_type: ResetType::ForceRestart,
+    };
+    assert_eq!(
+        serde_json::to_value(&body).unwrap(),
+        serde_json::json!({ "ResetType": "ForceRestart" })
+    );
+}
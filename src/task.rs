@@ -0,0 +1,90 @@
+use crate::common::*;
+
+/// Where a task is in its lifecycle, from `New` through to one of the end
+/// states `is_finished` checks for
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub enum TaskState {
+    New,
+    Starting,
+    Running,
+    Suspended,
+    Interrupted,
+    Pending,
+    Stopping,
+    Completed,
+    Killed,
+    Exception,
+    Service,
+    Cancelling,
+    Cancelled,
+    #[serde(other)]
+    Other,
+}
+
+impl TaskState {
+    /// Whether the task has stopped for good, successfully or not
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            TaskState::Completed | TaskState::Exception | TaskState::Killed | TaskState::Cancelled
+        )
+    }
+}
+
+/// A message a task reports about its progress or outcome
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct TaskMessage {
+    pub message: Option<String>,
+    #[serde(default)]
+    pub message_args: Vec<String>,
+    pub message_id: String,
+    pub severity: Option<String>,
+}
+
+/// A long-running operation tracked by the `TaskService`, e.g. a firmware update
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Task {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub end_time: Option<String>,
+    pub id: String,
+    #[serde(default)]
+    pub messages: Vec<TaskMessage>,
+    pub name: String,
+    pub percent_complete: Option<u32>,
+    pub start_time: Option<String>,
+    pub task_state: TaskState,
+    pub task_status: Option<String>,
+}
+
+pub type TaskCollection = Collection;
+
+#[test]
+fn test_task_parser() {
+    let test_data = include_str!("../tests/task.json");
+    let result: Task = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.task_state, TaskState::Completed);
+    assert!(result.task_state.is_finished());
+    assert_eq!(result.percent_complete, Some(100));
+    assert_eq!(result.messages[0].message_id, "IDRAC.2.8.PR19");
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_task_state_other() {
+    let state: TaskState = serde_json::from_str("\"Verifying\"").unwrap();
+    assert_eq!(state, TaskState::Other);
+    assert!(!state.is_finished());
+    let state: TaskState = serde_json::from_str("\"Running\"").unwrap();
+    assert!(!state.is_finished());
+}
+
+#[test]
+fn test_task_collection_parser() {
+    let test_data = include_str!("../tests/tasks.json");
+    let result: TaskCollection = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.members.len(), 2);
+    println!("result: {:#?}", result);
+}
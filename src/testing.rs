@@ -0,0 +1,141 @@
+//! Helpers for testing code built on this crate without real hardware,
+//! enabled by the `testing` feature. Point `Redfish::mock` at a local HTTP
+//! server (e.g. `wiremock` or `httpmock`) and serve it the `fixtures`.
+
+use crate::{ApiVersion, Config, Redfish, RedfishError, normalize_host};
+use reqwest::Url;
+use reqwest::blocking::Client;
+#[cfg(test)]
+use std::thread::JoinHandle;
+
+/// Responses captured from real BMCs, as served under `redfish/v1/`
+pub mod fixtures {
+    /// `Chassis/1/Power/` from an HP iLO
+    pub const POWER: &str = include_str!("../tests/power.json");
+    /// `Chassis/1/Thermal/` from an HP iLO
+    pub const THERMAL: &str = include_str!("../tests/chassis-thermal.json");
+    /// `Systems/1/SmartStorage/ArrayControllers/` from an HP iLO
+    pub const ARRAY_CONTROLLERS: &str = include_str!("../tests/array-controllers.json");
+    /// `Systems/1/SmartStorage/ArrayControllers/1/` from an HP iLO
+    pub const ARRAY_CONTROLLER: &str = include_str!("../tests/array-controller.json");
+    /// `Systems/1/SmartStorage/ArrayControllers/1/DiskDrives/16/` from an HP iLO
+    pub const DISK_DRIVE: &str = include_str!("../tests/disk-drive.json");
+}
+
+impl Redfish {
+    /// A client for a mock server at `base_url`, e.g. `http://127.0.0.1:8080`.
+    /// Requests go to `base_url/redfish/v1/...` without credentials, over
+    /// plain HTTP unless `base_url` is `https`, in which case any certificate
+    /// is accepted.
+    pub fn mock(base_url: &str) -> Result<Redfish, RedfishError> {
+        let url = Url::parse(base_url).map_err(|e| {
+            RedfishError::InvalidConfig(format!("{} is not a valid URL: {}", base_url, e))
+        })?;
+        let scheme = match url.scheme() {
+            "http" => "http",
+            "https" => "https",
+            other => {
+                return Err(RedfishError::InvalidConfig(format!(
+                    "mock servers are reached over http or https, not {}",
+                    other
+                )));
+            }
+        };
+        let client = Client::builder()
+            .danger_accept_invalid_certs(true)
+            .build()?;
+        let config = Config {
+            endpoint: normalize_host(base_url).to_string(),
+            api_version: Some(ApiVersion::V1),
+            ..Config::default()
+        };
+        let mut redfish = Redfish::new(client, config);
+        redfish.scheme = scheme;
+        Ok(redfish)
+    }
+}
+
+/// Answer `count` connections on a local port in turn, the response to each
+/// built by `respond` from the request's head. A response is everything after
+/// `HTTP/1.1 `, e.g. `"200 OK\r\nETag: W/\"7\"\r\n\r\n{}"`, and
+/// `Content-Length` is added for it. An empty response holds the connection
+/// open without answering. The server returns the head of every request.
+#[cfg(test)]
+pub(crate) fn serve_with(
+    count: usize,
+    mut respond: impl FnMut(&str) -> String + Send + 'static,
+) -> (u16, JoinHandle<Vec<String>>) {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let server = std::thread::spawn(move || {
+        let mut requests = Vec::new();
+        for _ in 0..count {
+            let (conn, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(conn);
+            let mut request = String::new();
+            let mut length = 0;
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                if let Some(l) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    length = l.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                line.clear();
+            }
+            reader.read_exact(&mut vec![0; length]).unwrap();
+            let response = respond(&request);
+            if response.is_empty() {
+                std::thread::sleep(std::time::Duration::from_secs(5));
+            } else {
+                let (head, body) = response
+                    .split_once("\r\n\r\n")
+                    .unwrap_or((response.as_str(), ""));
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    head,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests.push(request);
+        }
+        requests
+    });
+    (port, server)
+}
+
+/// `serve_with` answering one connection per entry of `responses`, in order
+#[cfg(test)]
+pub(crate) fn serve(responses: Vec<String>) -> (u16, JoinHandle<Vec<String>>) {
+    let count = responses.len();
+    let mut responses = responses.into_iter();
+    serve_with(count, move |_| responses.next().unwrap())
+}
+
+#[test]
+fn test_mock() {
+    let (port, server) = serve(vec![format!(
+        "200 OK\r\nContent-Type: application/json\r\n\r\n{}",
+        fixtures::POWER
+    )]);
+
+    let redfish = Redfish::mock(&format!("http://127.0.0.1:{}", port)).unwrap();
+    let power = redfish.get_power_status().unwrap();
+    assert_eq!(power.total_consumed_watts(), Some(590.0));
+    let requests = server.join().unwrap();
+    assert_eq!(
+        requests[0].lines().next(),
+        Some("GET /redfish/v1/Chassis/1/Power/ HTTP/1.1")
+    );
+    assert!(!requests[0].to_ascii_lowercase().contains("authorization"));
+
+    assert!(matches!(
+        Redfish::mock("ftp://127.0.0.1"),
+        Err(RedfishError::InvalidConfig(_))
+    ));
+}
@@ -1,6 +1,6 @@
 use crate::common::*;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct FansOemHp {
     #[serde(flatten)]
@@ -8,19 +8,19 @@ pub struct FansOemHp {
     pub location: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct FansOem {
     pub hp: FansOemHp,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Fan {
     pub current_reading: i64,
     pub fan_name: String,
     pub oem: FansOem,
-    pub status: SomeStatus,
+    pub status: ResourceStatus,
     pub units: String,
 }
 impl Status for Fan {
@@ -33,7 +33,7 @@ impl Status for Fan {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct TemperaturesOemHp {
     #[serde(flatten)]
@@ -42,13 +42,13 @@ pub struct TemperaturesOemHp {
     pub location_ymm: i64,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct TemperaturesOem {
     pub hp: TemperaturesOemHp,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Temperature {
     pub current_reading: i64,
@@ -59,7 +59,7 @@ pub struct Temperature {
     pub oem: TemperaturesOem,
     pub physical_context: String,
     pub reading_celsius: i64,
-    pub status: SomeStatus,
+    pub status: ResourceStatus,
     pub units: String,
     pub upper_threshold_critical: i64,
     pub upper_threshold_fatal: i64,
@@ -74,7 +74,7 @@ impl Status for Temperature {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Thermal {
     #[serde(flatten)]
@@ -82,11 +82,23 @@ pub struct Thermal {
     pub fans: Vec<Fan>,
     pub id: String,
     pub name: String,
+    #[serde(default)]
+    pub redundancy: Vec<FanRedundancy>,
     pub temperatures: Vec<Temperature>,
     #[serde(rename = "Type")]
     pub thermal_type: String,
 }
 
+/// A group of fans that back each other up. Only the fields needed to judge
+/// its health are kept, as services fill in the rest inconsistently.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct FanRedundancy {
+    pub member_id: Option<String>,
+    pub name: Option<String>,
+    pub status: Option<ResourceStatus>,
+}
+
 impl StatusVec for Thermal {
     fn get_vec(&self) -> Vec<Box<dyn Status>> {
         let mut v: Vec<Box<dyn Status>> = Vec::new();
@@ -100,9 +112,164 @@ impl StatusVec for Thermal {
     }
 }
 
+impl Thermal {
+    /// The temperature sensor with the highest reading, if there are any
+    pub fn hottest_sensor(&self) -> Option<&Temperature> {
+        self.temperatures.iter().max_by_key(|t| t.reading_celsius)
+    }
+
+    /// The temperature sensors reading at or above their critical threshold.
+    /// A threshold of zero means the sensor has none, as iLO reports it.
+    pub fn over_threshold(&self) -> Vec<&Temperature> {
+        self.temperatures
+            .iter()
+            .filter(|t| t.upper_threshold_critical > 0)
+            .filter(|t| t.reading_celsius >= t.upper_threshold_critical)
+            .collect()
+    }
+
+    /// The lowest speed among installed fans reporting in percent, if any do
+    pub fn min_fan_percent(&self) -> Option<u8> {
+        self.fans
+            .iter()
+            .filter(|f| f.units == "Percent" && f.status.state != Some(State::Absent))
+            .map(|f| f.current_reading.clamp(0, 100) as u8)
+            .min()
+    }
+
+    /// The installed fans whose health is other than `OK`. Empty fan bays
+    /// (`Absent`) are skipped.
+    pub fn failed_fans(&self) -> Vec<&Fan> {
+        self.fans
+            .iter()
+            .filter(|f| f.status.state != Some(State::Absent))
+            .filter(|f| !f.status.is_healthy())
+            .collect()
+    }
+
+    /// Whether any fan redundancy group reports degraded health, as when
+    /// fewer fans work than it needs. `false` if the chassis reports no groups.
+    pub fn any_fan_redundancy_lost(&self) -> bool {
+        self.redundancy
+            .iter()
+            .any(|r| r.status.is_some_and(|s| !s.is_healthy()))
+    }
+}
+
 #[test]
 fn test_thermal_parser() {
     let test_data = include_str!("../tests/chassis-thermal.json");
     let result: Thermal = serde_json::from_str(&test_data).unwrap();
     println!("result: {:#?}", result);
 }
+
+#[test]
+fn test_thermal_over_threshold() {
+    let test_data = include_str!("../tests/chassis-thermal.json");
+    let mut json: serde_json::Value = serde_json::from_str(&test_data).unwrap();
+    let result: Thermal = serde_json::from_value(json.clone()).unwrap();
+    assert!(result.over_threshold().is_empty());
+    assert_eq!(result.hottest_sensor().unwrap().name, "28-PCI 1");
+
+    let sensors = json["Temperatures"].as_array_mut().unwrap();
+    sensors[0]["ReadingCelsius"] = serde_json::json!(48);
+    sensors[1]["ReadingCelsius"] = serde_json::json!(69);
+    sensors[2]["UpperThresholdCritical"] = serde_json::json!(0);
+    sensors[2]["ReadingCelsius"] = serde_json::json!(90);
+    let result: Thermal = serde_json::from_value(json).unwrap();
+    let hot: Vec<&str> = result
+        .over_threshold()
+        .iter()
+        .map(|t| t.name.as_str())
+        .collect();
+    assert_eq!(hot, vec![result.temperatures[0].name.as_str()]);
+    assert_eq!(result.hottest_sensor().unwrap().reading_celsius, 90);
+
+    let mut json: serde_json::Value = serde_json::from_str(&test_data).unwrap();
+    json["Temperatures"] = serde_json::json!([]);
+    let empty: Thermal = serde_json::from_value(json).unwrap();
+    assert!(empty.hottest_sensor().is_none());
+}
+
+#[test]
+fn test_thermal_fans() {
+    let test_data = include_str!("../tests/chassis-thermal.json");
+    let result: Thermal = serde_json::from_str(&test_data).unwrap();
+    assert!(result.failed_fans().is_empty());
+    assert!(!result.any_fan_redundancy_lost());
+
+    let test_data = include_str!("../tests/thermal-fans.json");
+    let result: Thermal = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.min_fan_percent(), Some(0));
+    let failed: Vec<&str> = result
+        .failed_fans()
+        .iter()
+        .map(|f| f.fan_name.as_str())
+        .collect();
+    assert_eq!(failed, vec!["Fan 3"]);
+    assert!(result.any_fan_redundancy_lost());
+
+    let mut json: serde_json::Value = serde_json::from_str(&test_data).unwrap();
+    json["Fans"].as_array_mut().unwrap().truncate(2);
+    let result: Thermal = serde_json::from_value(json).unwrap();
+    assert_eq!(result.min_fan_percent(), Some(62));
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemperatureSummary {
+    pub ambient: Option<SensorExcerpt>,
+    pub exhaust: Option<SensorExcerpt>,
+    pub intake: Option<SensorExcerpt>,
+    pub internal: Option<SensorExcerpt>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemperatureReading {
+    pub data_source_uri: Option<String>,
+    pub device_name: Option<String>,
+    pub reading: Option<f64>,
+}
+
+/// `ThermalSubsystem/ThermalMetrics` from the newer thermal schema. Every
+/// metric is optional since implementations populate different subsets.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ThermalMetrics {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub air_flow_cubic_feet_per_minute: Option<SensorExcerpt>,
+    #[serde(rename = "EnergykWh")]
+    pub energy_kwh: Option<SensorExcerpt>,
+    pub id: String,
+    pub name: String,
+    pub power_watts: Option<SensorExcerpt>,
+    #[serde(default)]
+    pub temperature_readings_celsius: Vec<TemperatureReading>,
+    pub temperature_summary_celsius: Option<TemperatureSummary>,
+}
+
+#[test]
+fn test_thermal_metrics_parser() {
+    let test_data = include_str!("../tests/thermal-metrics.json");
+    let result: ThermalMetrics = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
+
+/// The `Thermal` resource of a chassis, relative to the service root
+pub(crate) fn thermal_path(chassis_id: &str) -> String {
+    format!("Chassis/{}/Thermal/", chassis_id)
+}
+
+#[test]
+fn test_thermal_path() {
+    assert_eq!(thermal_path("1"), "Chassis/1/Thermal/");
+    assert_eq!(thermal_path("Blade3"), "Chassis/Blade3/Thermal/");
+}
+
+#[test]
+fn test_thermal_roundtrip() {
+    assert_roundtrip::<Thermal>(include_str!("../tests/chassis-thermal.json"));
+    assert_roundtrip::<ThermalMetrics>(include_str!("../tests/thermal-metrics.json"));
+}
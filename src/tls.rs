@@ -0,0 +1,94 @@
+use rustls::client::{ServerCertVerified, ServerCertVerifier};
+use rustls::{Certificate, ServerName};
+use sha2::{Digest, Sha256};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+/// A SHA-256 certificate pin, along with the fingerprint of the last
+/// certificate that failed to match it so the failure can be reported as
+/// `RedfishError::CertificateMismatch` rather than an opaque handshake error
+#[derive(Debug)]
+pub(crate) struct CertificatePin {
+    pub(crate) expected: String,
+    pub(crate) mismatch: Mutex<Option<String>>,
+}
+
+impl CertificatePin {
+    /// Accepts the fingerprint as plain hex or colon separated, in either case
+    pub(crate) fn new(fingerprint: &str) -> Self {
+        let expected = fingerprint
+            .chars()
+            .filter(|c| c.is_ascii_hexdigit())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        CertificatePin {
+            expected,
+            mismatch: Mutex::new(None),
+        }
+    }
+
+    /// Take the fingerprint recorded by a failed handshake, if any
+    pub(crate) fn take_mismatch(&self) -> Option<String> {
+        self.mismatch.lock().ok().and_then(|mut m| m.take())
+    }
+}
+
+pub(crate) fn fingerprint(der: &[u8]) -> String {
+    Sha256::digest(der)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Trusts exactly the pinned leaf certificate. Chain and hostname validation are
+/// deliberately skipped, since BMC certificates are typically self-signed and
+/// issued for whatever name the factory gave them.
+struct PinnedCertVerifier {
+    pin: Arc<CertificatePin>,
+}
+
+impl ServerCertVerifier for PinnedCertVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        _intermediates: &[Certificate],
+        _server_name: &ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: SystemTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let found = fingerprint(&end_entity.0);
+        if found == self.pin.expected {
+            return Ok(ServerCertVerified::assertion());
+        }
+        if let Ok(mut mismatch) = self.pin.mismatch.lock() {
+            *mismatch = Some(found);
+        }
+        Err(rustls::Error::General(
+            "certificate fingerprint mismatch".to_string(),
+        ))
+    }
+}
+
+pub(crate) fn pinned_client_config(pin: Arc<CertificatePin>) -> rustls::ClientConfig {
+    rustls::ClientConfig::builder()
+        .with_safe_defaults()
+        .with_custom_certificate_verifier(Arc::new(PinnedCertVerifier { pin }))
+        .with_no_client_auth()
+}
+
+#[test]
+fn test_pin_normalization() {
+    let colons = CertificatePin::new("AB:CD:01:ef");
+    let plain = CertificatePin::new("abcd01EF");
+    assert_eq!(colons.expected, "abcd01ef");
+    assert_eq!(colons.expected, plain.expected);
+}
+
+#[test]
+fn test_fingerprint() {
+    assert_eq!(
+        fingerprint(b""),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+}
@@ -0,0 +1,115 @@
+use crate::error::RedfishError;
+use serde_json::Value;
+
+/// A queue of PATCH operations applied in order by `Redfish::transaction`.
+///
+/// Redfish has no notion of atomicity across resources, so this is best
+/// effort only: before each PATCH the properties it touches are read back,
+/// and if a later operation fails the ones already applied are reverted to
+/// those prior values. A revert can itself fail, and anything changed
+/// concurrently by another client will be overwritten by the revert.
+#[derive(Debug, Clone, Default)]
+pub struct Transaction {
+    pub(crate) operations: Vec<(String, Value)>,
+}
+
+impl Transaction {
+    pub fn new() -> Self {
+        Transaction::default()
+    }
+
+    /// Queue a PATCH of `body` against `api`
+    pub fn patch(mut self, api: &str, body: Value) -> Self {
+        self.operations.push((api.to_string(), body));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+}
+
+#[derive(Debug)]
+pub struct TransactionError {
+    /// The resource whose pre-read or PATCH failed
+    pub failed: String,
+    pub source: RedfishError,
+    /// Already-applied resources that could not be reverted
+    pub rollback_failures: Vec<(String, RedfishError)>,
+}
+
+impl ::std::fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "PATCH of {} failed: {}", self.failed, self.source)?;
+        if !self.rollback_failures.is_empty() {
+            let resources: Vec<&str> = self
+                .rollback_failures
+                .iter()
+                .map(|(api, _)| api.as_str())
+                .collect();
+            write!(f, " (rollback also failed for {})", resources.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl ::std::error::Error for TransactionError {
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Capture the current value of every property `patch` is about to change, in
+/// the same shape as `patch`, so it can be sent back to undo it. Properties
+/// that do not exist yet are captured as `null`.
+pub(crate) fn snapshot(current: &Value, patch: &Value) -> Value {
+    match patch {
+        Value::Object(fields) => {
+            let mut prior = serde_json::Map::new();
+            for (key, value) in fields {
+                let captured = match (value, current.get(key)) {
+                    (Value::Object(_), Some(existing @ Value::Object(_))) => {
+                        snapshot(existing, value)
+                    }
+                    (_, Some(existing)) => existing.clone(),
+                    (_, None) => Value::Null,
+                };
+                prior.insert(key.clone(), captured);
+            }
+            Value::Object(prior)
+        }
+        _ => current.clone(),
+    }
+}
+
+#[test]
+fn test_snapshot_captures_only_patched_properties() {
+    let current = serde_json::json!({
+        "Id": "1",
+        "AssetTag": "rack-4",
+        "Boot": {
+            "BootSourceOverrideEnabled": "Disabled",
+            "BootSourceOverrideTarget": "None",
+            "BootSourceOverrideMode": "UEFI"
+        }
+    });
+    let patch = serde_json::json!({
+        "AssetTag": "rack-5",
+        "Boot": {
+            "BootSourceOverrideTarget": "Pxe"
+        },
+        "HostName": "node07"
+    });
+    let expected = serde_json::json!({
+        "AssetTag": "rack-4",
+        "Boot": {
+            "BootSourceOverrideTarget": "None"
+        },
+        "HostName": null
+    });
+    assert_eq!(snapshot(&current, &patch), expected);
+}
@@ -0,0 +1,130 @@
+use crate::common::*;
+use std::path::PathBuf;
+
+/// When a staged update should be applied, sent as `@Redfish.OperationApplyTime`
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ApplyTime {
+    Immediate,
+    OnReset,
+    AtMaintenanceWindowStart,
+    InMaintenanceWindowOnReset,
+    OnStartUpdateRequest,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateService {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub firmware_inventory: Option<ODataId>,
+    pub http_push_uri: Option<String>,
+    pub id: String,
+    pub multipart_http_push_uri: Option<String>,
+    pub name: String,
+    pub service_enabled: Option<bool>,
+}
+
+impl UpdateService {
+    /// The URI to push a multipart firmware upload to. `MultipartHttpPushUri` is
+    /// preferred, but many BMCs also take the multipart form at `HttpPushUri`.
+    pub fn push_uri(&self) -> Option<&str> {
+        self.multipart_http_push_uri
+            .as_deref()
+            .or(self.http_push_uri.as_deref())
+    }
+}
+
+/// The collection of firmware components the update service knows about
+pub type FirmwareInventory = Collection;
+
+#[test]
+fn test_firmware_inventory_parser() {
+    let test_data = include_str!("../tests/firmware-inventory.json");
+    let result: FirmwareInventory = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
+
+/// One firmware component, e.g. the BMC, BIOS or a NIC
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SoftwareInventory {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub id: String,
+    pub manufacturer: Option<String>,
+    pub name: String,
+    pub release_date: Option<String>,
+    pub status: Option<SomeStatus>,
+    pub updateable: Option<bool>,
+    pub version: Option<String>,
+}
+
+#[test]
+fn test_software_inventory_parser() {
+    let test_data = include_str!("../tests/software-inventory.json");
+    let result: SoftwareInventory = serde_json::from_str(&test_data).unwrap();
+    println!("result: {:#?}", result);
+}
+
+/// The `UpdateParameters` part of a multipart firmware upload
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateParameters {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<String>,
+    #[serde(
+        rename = "@Redfish.OperationApplyTime",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub apply_time: Option<ApplyTime>,
+}
+
+/// A firmware image to upload, either read from disk as it is sent or already in memory
+#[derive(Debug, Clone)]
+pub enum FirmwareImage {
+    Path(PathBuf),
+    Bytes { file_name: String, data: Vec<u8> },
+}
+
+impl From<PathBuf> for FirmwareImage {
+    fn from(path: PathBuf) -> Self {
+        FirmwareImage::Path(path)
+    }
+}
+
+impl From<&std::path::Path> for FirmwareImage {
+    fn from(path: &std::path::Path) -> Self {
+        FirmwareImage::Path(path.to_path_buf())
+    }
+}
+
+#[test]
+fn test_update_service_parser() {
+    let test_data = include_str!("../tests/update-service.json");
+    let result: UpdateService = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.push_uri(), Some("/redfish/v1/UpdateService/upload"));
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_update_parameters() {
+    let params = UpdateParameters {
+        targets: vec!["/redfish/v1/Managers/1".to_string()],
+        apply_time: Some(ApplyTime::OnReset),
+    };
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "Targets": ["/redfish/v1/Managers/1"],
+            "@Redfish.OperationApplyTime": "OnReset",
+        })
+    );
+    let params = UpdateParameters {
+        targets: Vec::new(),
+        apply_time: None,
+    };
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({})
+    );
+}
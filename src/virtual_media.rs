@@ -0,0 +1,52 @@
+use crate::common::*;
+
+/// A virtual CD, DVD or USB drive a manager can attach a remote image to
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct VirtualMedia {
+    #[serde(flatten)]
+    pub odata: ODataResource,
+    pub connected_via: Option<String>,
+    pub id: String,
+    pub image: Option<String>,
+    pub image_name: Option<String>,
+    pub inserted: Option<bool>,
+    #[serde(default)]
+    pub media_types: Vec<String>,
+    pub name: String,
+    pub write_protected: Option<bool>,
+}
+
+/// The body of `VirtualMedia.InsertMedia`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InsertMedia<'a> {
+    pub image: &'a str,
+    pub inserted: bool,
+    pub write_protected: bool,
+}
+
+/// The body PATCHed to attach or detach an image on services without the
+/// `InsertMedia`/`EjectMedia` actions, e.g. older iLO firmware
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MediaImage<'a> {
+    pub image: Option<&'a str>,
+}
+
+#[test]
+fn test_virtual_media_parser() {
+    let test_data = include_str!("../tests/virtual-media.json");
+    let result: VirtualMedia = serde_json::from_str(&test_data).unwrap();
+    assert_eq!(result.inserted, Some(true));
+    assert_eq!(result.media_types, vec!["CD", "DVD"]);
+    println!("result: {:#?}", result);
+}
+
+#[test]
+fn test_media_image() {
+    assert_eq!(
+        serde_json::to_value(MediaImage { image: None }).unwrap(),
+        serde_json::json!({ "Image": null })
+    );
+}